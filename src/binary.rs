@@ -0,0 +1,245 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+
+use crate::{build_grid, Board, ParseError, ShapeError};
+
+const MAGIC: &[u8; 4] = b"GOLB";
+const VERSION: u8 = 1;
+/// Magic, version, then rows and cols as little-endian `u32`s.
+const HEADER_LEN: usize = MAGIC.len() + 1 + 4 + 4;
+
+/// Packs cells into bytes, 8 to a byte, least significant bit first.
+pub(crate) fn pack_bits(cells: impl Iterator<Item = bool>) -> Vec<u8> {
+    let mut bytes = vec![];
+    for (i, cell) in cells.enumerate() {
+        if i % 8 == 0 {
+            bytes.push(0);
+        }
+        if cell {
+            *bytes.last_mut().unwrap() |= 1 << (i % 8);
+        }
+    }
+    bytes
+}
+
+/// The inverse of [`pack_bits`], reading exactly `len` cells. `bytes` must hold at least that many.
+pub(crate) fn unpack_bits(bytes: &[u8], len: usize) -> Vec<bool> {
+    (0..len).map(|i| bytes[i / 8] & (1 << (i % 8)) != 0).collect()
+}
+
+/// A snapshot of a board's cells from [`Board::save_state`], stored bit-packed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BoardState {
+    rows: usize,
+    cols: usize,
+    cells: Vec<u8>,
+}
+
+impl BoardState {
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+}
+
+impl Board {
+    /// Takes a snapshot of the board's cells for [`Board::restore_state`]. Edge settings and
+    /// the rule aren't included, since restoring keeps the board's own.
+    pub fn save_state(&self) -> BoardState {
+        BoardState {
+            rows: self.grid.rows(),
+            cols: self.grid.cols(),
+            cells: pack_bits(self.grid.iter().copied()),
+        }
+    }
+
+    /// Overwrites the board's cells from a snapshot of a board the same size.
+    pub fn restore_state(&mut self, state: &BoardState) -> Result<(), ShapeError> {
+        if self.grid.size() != (state.rows, state.cols) {
+            return Err(ShapeError::Mismatch { expected: self.grid.size(), found: (state.rows, state.cols) });
+        }
+        let cells = unpack_bits(&state.cells, state.rows * state.cols);
+        self.set_each(|row, col| cells[row * state.cols + col]);
+        Ok(())
+    }
+
+    /// Serializes the board into a compact binary format: a 13 byte header holding the magic
+    /// `GOLB`, a format version, and the row and column counts, followed by the cells packed
+    /// 8 to a byte in row-major order.
+    ///
+    /// Edge settings and the rule aren't stored. Dimensions must fit in a `u32`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HEADER_LEN + (self.grid.rows() * self.grid.cols()).div_ceil(8));
+        bytes.extend_from_slice(MAGIC);
+        bytes.push(VERSION);
+        bytes.extend_from_slice(&(self.grid.rows() as u32).to_le_bytes());
+        bytes.extend_from_slice(&(self.grid.cols() as u32).to_le_bytes());
+        bytes.extend(pack_bits(self.grid.iter().copied()));
+        bytes
+    }
+
+    /// Encodes [`Board::to_bytes`] as URL-safe base64 without padding, for sharing in links.
+    pub fn to_base64(&self) -> String {
+        URL_SAFE_NO_PAD.encode(self.to_bytes())
+    }
+
+    /// Decodes a board written by [`Board::to_base64`].
+    pub fn from_base64(encoded: &str) -> Result<Board, ParseError> {
+        let bytes = URL_SAFE_NO_PAD.decode(encoded.trim()).map_err(|e| ParseError::Base64(e.to_string()))?;
+        Board::from_bytes(&bytes)
+    }
+
+    /// Returns a compact key for the pattern on the board that ignores where it sits: the row
+    /// and column counts of its bounding box as little-endian `u32`s, then the trimmed cells
+    /// packed as in [`Board::to_bytes`].
+    ///
+    /// Rotated or reflected copies get different signatures. A dead board's is all zeros.
+    pub fn signature(&self) -> Vec<u8> {
+        let trimmed = self.trim();
+        let mut bytes = Vec::with_capacity(8 + (trimmed.grid.rows() * trimmed.grid.cols()).div_ceil(8));
+        bytes.extend_from_slice(&(trimmed.grid.rows() as u32).to_le_bytes());
+        bytes.extend_from_slice(&(trimmed.grid.cols() as u32).to_le_bytes());
+        bytes.extend(pack_bits(trimmed.grid.iter().copied()));
+        bytes
+    }
+
+    /// Deserializes a board written by [`Board::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Board, ParseError> {
+        if bytes.len() < HEADER_LEN {
+            return Err(ParseError::Length { expected: HEADER_LEN, found: bytes.len() });
+        }
+        if &bytes[..4] != MAGIC {
+            return Err(ParseError::BadMagic);
+        }
+        if bytes[4] != VERSION {
+            return Err(ParseError::UnsupportedVersion(bytes[4]));
+        }
+        let rows = u32::from_le_bytes(bytes[5..9].try_into().unwrap()) as usize;
+        let cols = u32::from_le_bytes(bytes[9..13].try_into().unwrap()) as usize;
+
+        let cells = rows.saturating_mul(cols);
+        let expected = HEADER_LEN.saturating_add(cells.div_ceil(8));
+        if bytes.len() != expected {
+            return Err(ParseError::Length { expected, found: bytes.len() });
+        }
+        if cells == 0 {
+            // a header like 0x5 still describes an empty board
+            return Ok(Board::dead(0, 0));
+        }
+
+        let cells = unpack_bits(&bytes[HEADER_LEN..], cells);
+        Ok(Board::from_grid(build_grid(cells, cols).expect("the length was checked against the header")))
+    }
+
+    /// Creates a board of up to 64 cells from the bits of `bits`, where bit `row * cols + col`
+    /// (counting from the least significant) is the cell at `(row, col)`. Bits past the last
+    /// cell are ignored.
+    pub fn from_bits(bits: u64, rows: usize, cols: usize) -> Result<Board, ShapeError> {
+        let cells = rows.saturating_mul(cols);
+        if cells > u64::BITS as usize {
+            return Err(ShapeError::Capacity { capacity: u64::BITS as usize, requested: cells });
+        }
+        let mut board = Board::dead(rows, cols);
+        board.set_each(|row, col| bits & (1 << (row * cols + col)) != 0);
+        Ok(board)
+    }
+
+    /// The inverse of [`Board::from_bits`]. Fails if the board has more than 64 cells.
+    pub fn to_bits(&self) -> Result<u64, ShapeError> {
+        let cells = self.grid.rows() * self.grid.cols();
+        if cells > u64::BITS as usize {
+            return Err(ShapeError::Capacity { capacity: u64::BITS as usize, requested: cells });
+        }
+        Ok(self.grid.iter().enumerate().filter(|(_, &cell)| cell).fold(0, |bits, (i, _)| bits | 1 << i))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bytes_round_trip() {
+        for board in [Board::random(7, 13), Board::random(8, 8), Board::dead(0, 0)] {
+            assert_eq!(board, Board::from_bytes(&board.to_bytes()).unwrap());
+        }
+        assert_eq!(HEADER_LEN + 12, Board::random(7, 13).to_bytes().len());
+    }
+
+    #[test]
+    fn truncated_bytes_error() {
+        let bytes = Board::random(7, 13).to_bytes();
+        let len = bytes.len();
+
+        assert_eq!(
+            Err(ParseError::Length { expected: len, found: len - 1 }),
+            Board::from_bytes(&bytes[..len - 1])
+        );
+        assert_eq!(Err(ParseError::Length { expected: HEADER_LEN, found: 6 }), Board::from_bytes(&bytes[..6]));
+        assert_eq!(Err(ParseError::BadMagic), Board::from_bytes(&[0; HEADER_LEN]));
+    }
+
+    #[test]
+    fn bits_round_trip() {
+        // a glider, one row per 3 bits starting from the least significant
+        let bits = 0b111_100_010;
+        let glider = Board::from_bits(bits, 3, 3).unwrap();
+        assert_eq!("010\n001\n111\n".parse::<Board>().unwrap(), glider);
+        assert_eq!(Ok(bits), glider.to_bits());
+
+        assert_eq!(Err(ShapeError::Capacity { capacity: 64, requested: 72 }), Board::from_bits(0, 8, 9));
+        assert_eq!(Err(ShapeError::Capacity { capacity: 64, requested: 81 }), Board::dead(9, 9).to_bits());
+    }
+
+    #[test]
+    fn signatures_ignore_position() {
+        let glider: Board = ".O.\n..O\nOOO\n".parse().unwrap();
+        let (mut here, mut there) = (Board::dead(10, 10), Board::dead(12, 8));
+        here.stamp(&glider, 1, 2);
+        there.stamp(&glider, 7, 4);
+
+        assert_eq!(here.signature(), there.signature());
+        assert_eq!(vec![3, 0, 0, 0, 3, 0, 0, 0, 0b1110_0010, 0b1], here.signature());
+
+        let mut block = Board::dead(10, 10);
+        block.set_each(|row, col| (4..6).contains(&row) && (4..6).contains(&col));
+        assert_ne!(here.signature(), block.signature());
+    }
+
+    #[test]
+    fn base64_round_trip() {
+        for board in [Board::random(9, 11), Board::dead(0, 0)] {
+            let encoded = board.to_base64();
+            assert!(encoded.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+            assert_eq!(board, Board::from_base64(&encoded).unwrap());
+        }
+    }
+
+    #[test]
+    fn invalid_base64_errors() {
+        assert!(matches!(Board::from_base64("not base64!"), Err(ParseError::Base64(_))));
+        // valid base64 that isn't a board
+        assert_eq!(Err(ParseError::BadMagic), Board::from_base64(&URL_SAFE_NO_PAD.encode(b"NOPE\x01\0\0\0\0\0\0\0\0")));
+    }
+
+    #[test]
+    fn restores_saved_state() {
+        let original = Board::random(9, 11).with_edges(crate::EdgeModes::toroidal());
+        let state = original.save_state();
+        assert_eq!((9, 11), (state.rows(), state.cols()));
+
+        let mut board = original.clone();
+        board.advance_n(3);
+        board.toggle(4, 4);
+        board.restore_state(&state).unwrap();
+        assert_eq!(original, board);
+
+        assert_eq!(
+            Err(ShapeError::Mismatch { expected: (3, 3), found: (9, 11) }),
+            Board::dead(3, 3).restore_state(&state)
+        );
+    }
+}
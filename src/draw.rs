@@ -0,0 +1,296 @@
+use itertools::Itertools;
+
+use crate::Board;
+
+impl Board {
+    /// Sets the cells along the segment from `from` to `to`, both `(row, col)`, to `value` using
+    /// Bresenham's algorithm. Parts of the line off the board are skipped without being visited,
+    /// so far-off endpoints cost no more than the cells actually drawn.
+    pub fn draw_line(&mut self, from: (usize, usize), to: (usize, usize), value: bool) {
+        // step one cell at a time along the axis the line covers faster
+        let row_major = from.0.abs_diff(to.0) >= from.1.abs_diff(to.1);
+        let (major, minor) = if row_major {
+            ((from.0, to.0, self.rows()), (from.1, to.1, self.cols()))
+        } else {
+            ((from.1, to.1, self.cols()), (from.0, to.0, self.rows()))
+        };
+        let steps = major.0.abs_diff(major.1) as u128;
+        let rise = minor.0.abs_diff(minor.1) as u128;
+        // how far the minor axis has moved after `t` steps, rounding halves up as Bresenham does
+        let offset = |t: u128| match steps {
+            0 => 0,
+            _ => t * rise / steps + u128::from(2 * (t * rise % steps) >= steps),
+        };
+
+        let Some((first, last)) = reach(major.0, major.1 >= major.0, steps, major.2) else {
+            return;
+        };
+        let Some((low, high)) = reach(minor.0, minor.1 >= minor.0, rise, minor.2) else {
+            return;
+        };
+        // the offset only grows, so the steps keeping the minor axis on the board are contiguous
+        let start = first.max(partition_point(steps, |t| offset(t) < low));
+        let end = (last + 1).min(partition_point(steps, |t| offset(t) <= high));
+        for t in start..end {
+            let major = shift(major.0, major.1 >= major.0, t);
+            let minor = shift(minor.0, minor.1 >= minor.0, offset(t));
+            let (row, col) = if row_major { (major, minor) } else { (minor, major) };
+            self.set(row, col, value);
+        }
+    }
+
+    /// Sets the cells of the `height` x `width` rectangle with its top-left corner at
+    /// `(row, col)` to `value`, either all of them or just the border when `filled` is false.
+    /// The rectangle is clipped to the board.
+    pub fn draw_rect(&mut self, row: usize, col: usize, height: usize, width: usize, filled: bool, value: bool) {
+        if height == 0 || width == 0 {
+            return;
+        }
+        let (bottom, right) = (row.saturating_add(height - 1), col.saturating_add(width - 1));
+        // only the visible part is visited, and set_clipped covers an empty board
+        let visible_rows = row..=bottom.min(self.rows().saturating_sub(1));
+        let visible_cols = col..=right.min(self.cols().saturating_sub(1));
+        for (r, c) in visible_rows.cartesian_product(visible_cols) {
+            if filled || r == row || r == bottom || c == col || c == right {
+                self.set_clipped(r as i64, c as i64, value);
+            }
+        }
+    }
+
+    /// Sets the cells of a circle of `radius` around `center`, a `(row, col)`, to `value`.
+    ///
+    /// The outline is traced with the midpoint circle algorithm, while a filled circle covers
+    /// every cell whose distance from the center is at most `radius`. Parts off the board are
+    /// skipped without being visited, however large the circle.
+    pub fn draw_circle(&mut self, center: (usize, usize), radius: usize, filled: bool, value: bool) {
+        let (rows, cols) = (self.rows(), self.cols());
+        let radius = radius as u128;
+        if filled {
+            let (Some((top, bottom)), Some((left, right))) = (reach_both(center.0, radius, rows), reach_both(center.1, radius, cols))
+            else {
+                return;
+            };
+            for (row, col) in (top..=bottom).cartesian_product(left..=right) {
+                let (dr, dc) = (center.0.abs_diff(row) as u128, center.1.abs_diff(col) as u128);
+                if (dr * dr).checked_add(dc * dc).is_some_and(|d| d <= radius * radius) {
+                    self.set(row, col, value);
+                }
+            }
+            return;
+        }
+
+        // the midpoint algorithm visits each `y` of an octant with `x` as the largest value where
+        // `x * (x - 1) < radius² - y²`, which can be found directly rather than stepped to
+        let x_at = |y: u128| {
+            let rest = radius * radius - y * y;
+            let root = rest.isqrt();
+            if (root + 1) * root < rest { root + 1 } else { root }
+        };
+        let last_y = partition_point(radius, |y| x_at(y) >= y) - 1;
+
+        // each reflection `(dr, dc)`, as whether the row moves by `y` and whether each moves forward
+        let octants = [
+            (true, true, true), (false, true, true), (false, true, false), (true, true, false),
+            (true, false, false), (false, false, false), (false, false, true), (true, false, true),
+        ];
+        for (row_by_y, down, right) in octants {
+            let (y_axis, x_axis) = if row_by_y {
+                ((center.0, down, rows), (center.1, right, cols))
+            } else {
+                ((center.1, right, cols), (center.0, down, rows))
+            };
+            let Some(((y_low, y_high), (x_low, x_high))) =
+                reach(y_axis.0, y_axis.1, last_y, y_axis.2).zip(reach(x_axis.0, x_axis.1, radius, x_axis.2))
+            else {
+                continue;
+            };
+            // `x` only shrinks as `y` grows, so the `y`s keeping it on the board are contiguous
+            let start = y_low.max(partition_point(last_y, |y| x_at(y) > x_high));
+            let end = (y_high + 1).min(partition_point(last_y, |y| x_at(y) >= x_low));
+            for y in start..end {
+                let (along_y, along_x) = (shift(y_axis.0, y_axis.1, y), shift(x_axis.0, x_axis.1, x_at(y)));
+                let (row, col) = if row_by_y { (along_y, along_x) } else { (along_x, along_y) };
+                self.set(row, col, value);
+            }
+        }
+    }
+
+    /// Sets the cell at `(row, col)` if it's on the board, and otherwise does nothing.
+    fn set_clipped(&mut self, row: i64, col: i64, value: bool) {
+        if let (Ok(row), Ok(col)) = (usize::try_from(row), usize::try_from(col)) {
+            if row < self.rows() && col < self.cols() {
+                self.set(row, col, value);
+            }
+        }
+    }
+}
+
+/// Returns the range of distances, up to `limit`, that can be moved from `start` toward higher
+/// indices if `forward` or lower ones otherwise while staying within `0..len`.
+fn reach(start: usize, forward: bool, limit: u128, len: usize) -> Option<(u128, u128)> {
+    let (start, len) = (start as u128, len as u128);
+    let (low, high) = if forward {
+        (0, (len.checked_sub(1)?).checked_sub(start)?)
+    } else {
+        (start.saturating_sub(len.checked_sub(1)?), start)
+    };
+    (low <= high.min(limit)).then_some((low, high.min(limit)))
+}
+
+/// Returns the first and last index within `0..len` that are at most `radius` from `center`.
+fn reach_both(center: usize, radius: u128, len: usize) -> Option<(usize, usize)> {
+    let first = (center as u128).saturating_sub(radius);
+    let last = (center as u128 + radius).min((len as u128).checked_sub(1)?);
+    (first <= last).then_some((first as usize, last as usize))
+}
+
+/// Moves `by` from `start` in the given direction. The result must be on the board.
+fn shift(start: usize, forward: bool, by: u128) -> usize {
+    let start = start as u128;
+    (if forward { start + by } else { start - by }) as usize
+}
+
+/// Returns the first value in `0..=high` for which `pred` is false, or `high + 1` if there is none,
+/// given that `pred` is true up to some point and false after it.
+fn partition_point<F: Fn(u128) -> bool>(high: u128, pred: F) -> u128 {
+    let (mut low, mut high) = (0, high + 1);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if pred(mid) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set_cells(board: &Board) -> Vec<(usize, usize)> {
+        board.live_cells().collect()
+    }
+
+    #[test]
+    fn draws_diagonal_and_horizontal_lines() {
+        let mut board = Board::dead(5, 8);
+        board.draw_line((0, 0), (3, 3), true);
+        assert_eq!(vec![(0, 0), (1, 1), (2, 2), (3, 3)], set_cells(&board));
+
+        let mut board = Board::dead(5, 8);
+        board.draw_line((4, 6), (4, 2), true);
+        assert_eq!(vec![(4, 2), (4, 3), (4, 4), (4, 5), (4, 6)], set_cells(&board));
+        board.draw_line((4, 3), (4, 4), false);
+        assert_eq!(vec![(4, 2), (4, 5), (4, 6)], set_cells(&board));
+    }
+
+    #[test]
+    fn draws_filled_rectangle() {
+        let mut board = Board::dead(6, 6);
+        board.draw_rect(1, 2, 3, 4, true, true);
+        assert_eq!((1..4).cartesian_product(2..6).collect_vec(), set_cells(&board));
+
+        board.draw_rect(2, 3, 1, 2, true, false);
+        assert_eq!(10, board.population());
+        assert_eq!(Some(false), board.get(2, 4));
+    }
+
+    #[test]
+    fn draws_rectangle_outline() {
+        let mut board = Board::dead(6, 6);
+        board.draw_rect(1, 1, 4, 4, false, true);
+        let border = (1..5).cartesian_product(1..5)
+            .filter(|&(row, col)| row == 1 || row == 4 || col == 1 || col == 4)
+            .collect_vec();
+        assert_eq!(border, set_cells(&board));
+
+        // clipped on the right and bottom, so only the top and left sides remain
+        let mut board = Board::dead(4, 4);
+        board.draw_rect(2, 2, 5, 5, false, true);
+        assert_eq!(vec![(2, 2), (2, 3), (3, 2)], set_cells(&board));
+    }
+
+    #[test]
+    fn draws_filled_circle() {
+        let mut board = Board::dead(9, 9);
+        board.draw_circle((4, 4), 3, true, true);
+
+        for inside in [(4, 4), (1, 4), (4, 7), (2, 2), (6, 5)] {
+            assert_eq!(Some(true), board.get(inside.0, inside.1), "{inside:?}");
+        }
+        for outside in [(0, 4), (1, 1), (7, 7), (4, 8)] {
+            assert_eq!(Some(false), board.get(outside.0, outside.1), "{outside:?}");
+        }
+        assert_eq!(29, board.population());
+
+        // a huge circle only visits the cells on the board
+        let mut corner = Board::dead(4, 4);
+        corner.draw_circle((0, 0), usize::MAX, true, true);
+        assert_eq!(16, corner.population());
+        let mut corner = Board::dead(4, 4);
+        corner.draw_circle((3, 3), 3, true, true);
+        assert_eq!(11, corner.population());
+    }
+
+    #[test]
+    fn draws_clipped_circle_outline() {
+        let mut board = Board::dead(9, 9);
+        board.draw_circle((4, 4), 2, false, true);
+        assert_eq!(12, board.population());
+        assert_eq!(Some(false), board.get(4, 4));
+        assert_eq!(Some(true), board.get(2, 4));
+
+        let mut corner = Board::dead(4, 4);
+        corner.draw_circle((0, 0), 2, false, true);
+        assert_eq!(vec![(0, 2), (1, 2), (2, 0), (2, 1)], set_cells(&corner));
+    }
+
+    #[test]
+    fn huge_circles_only_visit_the_board() {
+        // the far left of a huge circle is nearly straight as it crosses the board
+        let mut board = Board::dead(4, 4);
+        board.draw_circle((1, 1_000_000_000), 999_999_998, false, true);
+        assert_eq!(vec![(0, 2), (1, 2), (2, 2), (3, 2)], set_cells(&board));
+
+        let mut board = Board::dead(4, 4);
+        board.draw_circle((1, 1), usize::MAX, false, true);
+        assert_eq!(0, board.population());
+
+        // a center past the end of the board stays there rather than wrapping to just above it
+        for filled in [false, true] {
+            let mut board = Board::dead(4, 4);
+            board.draw_circle((usize::MAX, 1), 2, filled, true);
+            assert_eq!(0, board.population());
+        }
+    }
+
+    #[test]
+    fn far_off_endpoints_only_visit_the_board() {
+        let mut board = Board::dead(4, 4);
+        board.draw_line((1, 1), (usize::MAX, usize::MAX), true);
+        assert_eq!(vec![(1, 1), (2, 2), (3, 3)], set_cells(&board));
+
+        let mut board = Board::dead(4, 4);
+        board.draw_line((usize::MAX, 2), (0, 2), true);
+        board.draw_line((0, 0), (1, usize::MAX), true);
+        assert_eq!(vec![(0, 0), (0, 1), (0, 2), (0, 3), (1, 2), (2, 2), (3, 2)], set_cells(&board));
+
+        let mut board = Board::dead(4, 4);
+        board.draw_line((usize::MAX, 0), (usize::MAX - 5, usize::MAX), true);
+        assert_eq!(0, board.population());
+    }
+
+    #[test]
+    fn lines_are_clipped() {
+        let mut board = Board::dead(4, 4);
+        board.draw_line((1, 2), (1, 10), true);
+        assert_eq!(vec![(1, 2), (1, 3)], set_cells(&board));
+
+        // a shallow line steps down one row halfway along
+        let mut board = Board::dead(4, 4);
+        board.draw_line((0, 0), (1, 3), true);
+        assert_eq!(vec![(0, 0), (0, 1), (1, 2), (1, 3)], set_cells(&board));
+    }
+}
@@ -0,0 +1,66 @@
+use std::fs::{self, File};
+use std::io::{self, BufWriter};
+use std::path::Path;
+
+use crate::Board;
+
+impl Board {
+    /// Writes the board and its following generations to `dir` as `frame_0000.png`,
+    /// `frame_0001.png` and so on, `frames` files in all, creating `dir` if it's missing.
+    ///
+    /// Each cell is drawn as a `cell_size` square, black if alive and white if dead, as in
+    /// [`Board::to_svg`].
+    pub fn render_frames(mut self, dir: &Path, frames: usize, cell_size: u32) -> io::Result<()> {
+        fs::create_dir_all(dir)?;
+        for frame in 0..frames {
+            if frame > 0 {
+                self.advance();
+            }
+            self.write_png(&dir.join(format!("frame_{frame:04}.png")), cell_size)?;
+        }
+        Ok(())
+    }
+
+    fn write_png(&self, path: &Path, cell_size: u32) -> io::Result<()> {
+        let size = cell_size as usize;
+        let (width, height) = (self.cols() * size, self.rows() * size);
+        let too_large = || io::Error::new(io::ErrorKind::InvalidInput, "the image is too large for a PNG");
+
+        let mut encoder = png::Encoder::new(
+            BufWriter::new(File::create(path)?),
+            u32::try_from(width).map_err(|_| too_large())?,
+            u32::try_from(height).map_err(|_| too_large())?,
+        );
+        encoder.set_color(png::ColorType::Grayscale);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let pixels: Vec<u8> = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (y / size, x / size)))
+            .map(|(row, col)| if self.grid[row][col] { 0 } else { 255 })
+            .collect();
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&pixels)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_numbered_frames() {
+        let dir = std::env::temp_dir().join(format!("game_of_life_frames_{}", std::process::id()));
+        let glider: Board = ".O...\n..O..\nOOO..\n.....\n.....\n".parse().unwrap();
+        glider.render_frames(&dir.join("nested"), 3, 4).unwrap();
+
+        let mut names = fs::read_dir(dir.join("nested")).unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect::<Vec<_>>();
+        names.sort();
+        assert_eq!(vec!["frame_0000.png", "frame_0001.png", "frame_0002.png"], names);
+        assert!(fs::read(dir.join("nested/frame_0002.png")).unwrap().starts_with(b"\x89PNG"));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,131 @@
+use std::fmt::Display;
+
+use grid::Grid;
+use itertools::Itertools;
+
+use crate::{build_grid, Rule};
+
+/// `(row, col)` offsets of the 6 neighbors of a cell in an even row. Odd rows are shifted half a
+/// cell to the right, so their diagonal neighbors are one column further right.
+const EVEN_ROW_OFFSETS: [(isize, isize); 6] = [(-1, -1), (-1, 0), (0, -1), (0, 1), (1, -1), (1, 0)];
+const ODD_ROW_OFFSETS: [(isize, isize); 6] = [(-1, 0), (-1, 1), (0, -1), (0, 1), (1, 0), (1, 1)];
+
+/// A Life-like automaton on a hexagonal grid, stored in "odd-r" offset coordinates where every
+/// odd row is shifted half a cell to the right. Cells beyond the edges are dead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HexBoard {
+    grid: Grid<bool>,
+    rule: Rule,
+}
+
+impl Display for HexBoard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in 0..self.grid.rows() {
+            if row % 2 == 1 {
+                write!(f, " ")?;
+            }
+            let cells = self.grid.iter_row(row).map(|&alive| if alive { 'O' } else { '.' });
+            writeln!(f, "{}", cells.format(" "))?;
+        }
+        Ok(())
+    }
+}
+
+impl HexBoard {
+    /// The default hexagonal rule, `B2/S34`.
+    pub fn default_rule() -> Rule {
+        Rule::new(&[2], &[3, 4])
+    }
+
+    /// Creates a board of `rows` x `cols` dead cells using [`HexBoard::default_rule`].
+    pub fn dead(rows: usize, cols: usize) -> Self {
+        HexBoard { grid: Grid::init(rows, cols, false), rule: HexBoard::default_rule() }
+    }
+
+    /// Creates a board of `rows` x `cols` with every cell initialized randomly, using
+    /// [`HexBoard::default_rule`].
+    pub fn random(rows: usize, cols: usize) -> Self {
+        let mut grid = Grid::new(rows, cols);
+        grid.fill_with(rand::random::<bool>);
+        HexBoard { grid, rule: HexBoard::default_rule() }
+    }
+
+    /// Returns the board with its rule replaced. Only neighbor counts up to 6 are reachable.
+    pub fn with_rule(mut self, rule: Rule) -> Self {
+        self.rule = rule;
+        self
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<bool> {
+        self.grid.get(row, col).copied()
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, alive: bool) {
+        self.grid[row][col] = alive;
+    }
+
+    pub fn population(&self) -> usize {
+        self.grid.iter().filter(|&&cell| cell).count()
+    }
+
+    pub fn advance(&mut self) {
+        let (rows, cols) = (self.grid.rows(), self.grid.cols());
+        let new_state = (0..rows).cartesian_product(0..cols)
+            .map(|(row, col)| self.rule.next_state(self.grid[row][col], self.count_live_neighbors(row, col)))
+            .collect_vec();
+
+        self.grid = build_grid(new_state, cols).expect("there's one new state per cell");
+    }
+
+    pub fn count_live_neighbors(&self, row: usize, col: usize) -> usize {
+        let offsets = if row % 2 == 1 { &ODD_ROW_OFFSETS } else { &EVEN_ROW_OFFSETS };
+        offsets.iter()
+            .filter_map(|&(dr, dc)| Some((row.checked_add_signed(dr)?, col.checked_add_signed(dc)?)))
+            .filter(|&(r, c)| self.get(r, c).unwrap_or(false))
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_six_neighbors() {
+        for center in [(2, 2), (3, 2)] {
+            let mut board = HexBoard::dead(6, 6);
+            for r in 0..6 {
+                for c in 0..6 {
+                    board.set(r, c, true);
+                }
+            }
+            // every cell is alive, so the count is exactly the number of hex neighbors
+            assert_eq!(6, board.count_live_neighbors(center.0, center.1));
+
+            let mut board = HexBoard::dead(6, 6);
+            board.set(center.0, center.1 + 1, true);
+            board.set(center.0, center.1 + 2, true);
+            assert_eq!(1, board.count_live_neighbors(center.0, center.1));
+        }
+
+        // the diagonal neighbors depend on which way the row is shifted
+        let mut board = HexBoard::dead(6, 6);
+        board.set(1, 1, true);
+        assert_eq!(1, board.count_live_neighbors(2, 1));
+        assert_eq!(1, board.count_live_neighbors(2, 2));
+        assert_eq!(0, board.count_live_neighbors(2, 0));
+        assert_eq!(0, board.count_live_neighbors(2, 3));
+    }
+
+    #[test]
+    fn advances_and_displays() {
+        let mut board = HexBoard::dead(3, 3);
+        board.set(1, 0, true);
+        board.set(1, 1, true);
+        assert_eq!(". . .\n O O .\n. . .\n", board.to_string());
+
+        // under B2/S34 the pair dies, and the cells touching both are born
+        board.advance();
+        assert_eq!(". O .\n . . .\n. O .\n", board.to_string());
+    }
+}
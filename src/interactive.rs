@@ -0,0 +1,171 @@
+use std::io::{self, Write};
+use std::time::Duration;
+
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
+use crossterm::{cursor, execute, terminal};
+
+use crate::Board;
+
+/// Something the user asked the interactive mode to do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Step,
+    ToggleAutoRun,
+    Randomize,
+    Quit,
+}
+
+impl Action {
+    /// Maps a key press to its action, if it has one. Releases and repeats are ignored.
+    pub fn from_key(key: KeyEvent) -> Option<Action> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        match key.code {
+            KeyCode::Char(' ') => Some(Action::Step),
+            KeyCode::Char('p') => Some(Action::ToggleAutoRun),
+            KeyCode::Char('r') => Some(Action::Randomize),
+            KeyCode::Char('q') | KeyCode::Esc => Some(Action::Quit),
+            _ => None,
+        }
+    }
+}
+
+/// The state of an interactive session, kept separate from the terminal so it can be driven
+/// directly in tests.
+#[derive(Debug, Clone)]
+pub struct Session {
+    pub board: Board,
+    pub generation: u64,
+    pub auto_run: bool,
+    pub quit: bool,
+}
+
+impl Session {
+    /// Starts a paused session on `board`.
+    pub fn new(board: Board) -> Self {
+        Session { board, generation: 0, auto_run: false, quit: false }
+    }
+
+    pub fn apply(&mut self, action: Action) {
+        match action {
+            Action::Step => self.step(),
+            Action::ToggleAutoRun => self.auto_run = !self.auto_run,
+            Action::Randomize => {
+                self.board = Board::random(self.board.rows(), self.board.cols())
+                    .with_edges(self.board.edges())
+                    .with_rule(self.board.rule());
+                self.generation = 0;
+            }
+            Action::Quit => self.quit = true,
+        }
+    }
+
+    /// Handles one frame of time passing, advancing the board if auto-run is on.
+    pub fn tick(&mut self) {
+        if self.auto_run {
+            self.step();
+        }
+    }
+
+    fn step(&mut self) {
+        self.board.advance();
+        self.generation += 1;
+    }
+}
+
+/// Runs an interactive session in the terminal until the user quits.
+///
+/// Space steps once, `p` toggles running automatically every `frame`, `r` randomizes the board
+/// and `q` quits.
+pub fn run(board: Board, frame: Duration) -> io::Result<()> {
+    let mut stdout = io::stdout();
+    terminal::enable_raw_mode()?;
+    execute!(stdout, terminal::EnterAlternateScreen, cursor::Hide)?;
+
+    let result = event_loop(&mut Session::new(board), frame, &mut stdout);
+
+    execute!(stdout, cursor::Show, terminal::LeaveAlternateScreen)?;
+    terminal::disable_raw_mode()?;
+    result
+}
+
+fn event_loop(session: &mut Session, frame: Duration, stdout: &mut io::Stdout) -> io::Result<()> {
+    while !session.quit {
+        execute!(stdout, cursor::MoveTo(0, 0))?;
+        // raw mode doesn't translate newlines into carriage returns
+        let state = if session.auto_run { "running" } else { "paused" };
+        write!(stdout, "{}", session.board.to_string().replace('\n', "\r\n"))?;
+        write!(stdout, "generation {} ({state}) - space: step, p: run/pause, r: randomize, q: quit\r\n", session.generation)?;
+        stdout.flush()?;
+
+        if event::poll(frame)? {
+            if let Event::Key(key) = event::read()? {
+                if let Some(action) = Action::from_key(key) {
+                    session.apply(action);
+                }
+            }
+        } else {
+            session.tick();
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+    use crate::{EdgeModes, Rule};
+
+    fn press(c: char) -> KeyEvent {
+        KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE)
+    }
+
+    fn handle(session: &mut Session, c: char) {
+        session.apply(Action::from_key(press(c)).unwrap());
+    }
+
+    #[test]
+    fn key_sequence_drives_session() {
+        let mut blinker = Board::dead(5, 5);
+        blinker.set_each(|row, col| col == 2 && (1..=3).contains(&row));
+        let mut session = Session::new(blinker.clone());
+
+        assert_eq!(None, Action::from_key(press('x')));
+
+        handle(&mut session, ' ');
+        assert_eq!(1, session.generation);
+        assert_eq!(blinker.next_generation(), session.board);
+
+        // paused sessions ignore ticks until auto-run is toggled on
+        session.tick();
+        assert_eq!(1, session.generation);
+        handle(&mut session, 'p');
+        session.tick();
+        session.tick();
+        assert_eq!(3, session.generation);
+        handle(&mut session, 'p');
+        session.tick();
+        assert_eq!(3, session.generation);
+
+        handle(&mut session, 'r');
+        assert_eq!(0, session.generation);
+        assert_eq!((5, 5), (session.board.rows(), session.board.cols()));
+
+        assert!(!session.quit);
+        handle(&mut session, 'q');
+        assert!(session.quit);
+    }
+
+    #[test]
+    fn randomizing_keeps_edges_and_rule() {
+        let rule: Rule = "B36/S23".parse().unwrap();
+        let board = Board::dead(6, 7).with_edges(EdgeModes::toroidal()).with_rule(rule);
+        let mut session = Session::new(board);
+
+        handle(&mut session, 'r');
+        assert_eq!(EdgeModes::toroidal(), session.board.edges());
+        assert_eq!(rule, session.board.rule());
+    }
+}
@@ -0,0 +1,33 @@
+use crate::Board;
+
+impl Board {
+    /// Renders the board and the next `steps` generations as a JSON array of frames, each one
+    /// an array of rows of `0` for dead and `1` for alive cells. The first frame is the board
+    /// as it is now.
+    pub fn frames_json(&self, steps: usize) -> String {
+        let frames: Vec<Vec<Vec<u8>>> = self.trace(steps).iter()
+            .map(|board| {
+                (0..board.rows())
+                    .map(|row| board.grid.iter_row(row).map(|&alive| alive as u8).collect())
+                    .collect()
+            })
+            .collect();
+        serde_json::to_string(&frames).expect("nested arrays of numbers always serialize")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glider_frames_parse_back() {
+        let glider: Board = ".O...\n..O..\nOOO..\n.....\n".parse().unwrap();
+        let frames: Vec<Vec<Vec<u8>>> = serde_json::from_str(&glider.frames_json(4)).unwrap();
+
+        assert_eq!(5, frames.len());
+        assert_eq!(vec![vec![0, 1, 0, 0, 0], vec![0, 0, 1, 0, 0], vec![1, 1, 1, 0, 0], vec![0; 5]], frames[0]);
+        assert!(frames.iter().all(|frame| frame.len() == 4 && frame.iter().all(|row| row.len() == 5)));
+        assert_eq!("[[]]", Board::dead(0, 0).frames_json(0));
+    }
+}
@@ -0,0 +1,104 @@
+use std::collections::HashMap;
+
+use crate::{Board, Transform};
+
+/// Well-known patterns as `(name, period, cells)`, in one phase and orientation each.
+const PATTERNS: [(&str, usize, &str); 11] = [
+    ("block", 1, "OO\nOO"),
+    ("beehive", 1, ".OO.\nO..O\n.OO."),
+    ("loaf", 1, ".OO.\nO..O\n.O.O\n..O."),
+    ("boat", 1, "OO.\nO.O\n.O."),
+    ("ship", 1, "OO.\nO.O\n.OO"),
+    ("tub", 1, ".O.\nO.O\n.O."),
+    ("pond", 1, ".OO.\nO..O\nO..O\n.OO."),
+    ("blinker", 2, "OOO"),
+    ("toad", 2, ".OOO\nOOO."),
+    ("glider", 4, ".O.\n..O\nOOO"),
+    ("lwss", 4, ".O..O\nO....\nO...O\nOOOO."),
+];
+
+/// The bucket [`Board::census`] uses for clusters that match nothing in the library.
+pub const UNKNOWN: &str = "unknown";
+
+/// A trimmed pattern in whichever orientation sorts first, as `(rows, cols, cells)`.
+type CanonicalForm = (usize, usize, Vec<bool>);
+
+impl Board {
+    /// Tallies the connected clusters on the board (see [`Board::clusters`]) by the name of the
+    /// library pattern each one is, in any phase, orientation or position. Clusters matching
+    /// no known pattern are counted under `"unknown"`.
+    ///
+    /// Patterns close enough to touch form a single cluster, so they count as one unknown.
+    pub fn census(&self) -> HashMap<String, usize> {
+        let names = library_forms();
+        let mut census = HashMap::new();
+        for cluster in self.clusters() {
+            let name = names.get(&self.isolate(&cluster).canonical_form()).copied().unwrap_or(UNKNOWN);
+            *census.entry(name.to_string()).or_insert(0) += 1;
+        }
+        census
+    }
+
+    fn canonical_form(&self) -> CanonicalForm {
+        let trimmed = self.trim();
+        Transform::ALL.into_iter()
+            .map(|t| {
+                let board = trimmed.transform(t);
+                (board.rows(), board.cols(), board.grid.into_vec())
+            })
+            .min()
+            .expect("there's always at least one transform")
+    }
+}
+
+/// Maps the canonical form of every phase of every library pattern to its name.
+fn library_forms() -> HashMap<CanonicalForm, &'static str> {
+    let mut forms = HashMap::new();
+    for (name, period, cells) in PATTERNS {
+        let mut board: Board = cells.parse().expect("library patterns are well formed");
+        for _ in 0..period {
+            forms.insert(board.canonical_form(), name);
+            board.advance_growing(1);
+        }
+    }
+    forms
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn census_of_blocks_and_blinker() {
+        let board: Board = "\
+            OO......OOO\n\
+            OO.........\n\
+            ...........\n\
+            .....OO....\n\
+            .....OO....\n"
+            .parse()
+            .unwrap();
+        let census = board.census();
+
+        assert_eq!(2, census.len());
+        assert_eq!(Some(&2), census.get("block"));
+        assert_eq!(Some(&1), census.get("blinker"));
+    }
+
+    #[test]
+    fn census_recognizes_other_phases_and_unknowns() {
+        let mut board = Board::dead(20, 20);
+        let glider: Board = ".O.\n..O\nOOO".parse().unwrap();
+        let mut glider = glider.transform(Transform::Rotate90);
+        glider.advance_growing(1);
+        board.stamp(&glider.trim(), 1, 1);
+        board.stamp(&"OOO\n...\n...".parse::<Board>().unwrap().transform(Transform::Rotate90), 10, 10);
+        board.stamp(&"OO\nO.".parse().unwrap(), 15, 2);
+
+        let census = board.census();
+        assert_eq!(Some(&1), census.get("glider"));
+        assert_eq!(Some(&1), census.get("blinker"));
+        assert_eq!(Some(&1), census.get(UNKNOWN));
+        assert!(Board::dead(4, 4).census().is_empty());
+    }
+}
@@ -0,0 +1,150 @@
+use crate::{Board, ParseError};
+
+/// A node of a macrocell quadtree. Index 0 always refers to the empty node of any level.
+enum Node {
+    /// An 8x8 level-3 leaf, stored as one bitmask per row with bit `c` set for column `c`.
+    Leaf([u8; 8]),
+    /// A 2^level square split into `[nw, ne, sw, se]` quadrants.
+    Branch { level: u32, children: [usize; 4] },
+}
+
+impl Node {
+    fn level(&self) -> u32 {
+        match self {
+            Node::Leaf(_) => 3,
+            Node::Branch { level, .. } => *level,
+        }
+    }
+}
+
+impl Board {
+    /// Parses a two-state pattern in Golly's macrocell (`.mc`) format.
+    ///
+    /// The header and `#` lines are skipped, and the last node is taken as the root. The quadtree
+    /// is expanded into a board cropped to the pattern's live cells, so a pattern with no live
+    /// cells gives an empty 0x0 board.
+    pub fn from_macrocell(input: &str) -> Result<Board, ParseError> {
+        let mut nodes: Vec<Node> = vec![];
+        for (i, line) in input.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('[') || line.starts_with('#') {
+                continue;
+            }
+            let syntax = |reason: &str| ParseError::Syntax { line: i + 1, reason: reason.to_string() };
+
+            let node = if line.starts_with(['.', '*', '$']) {
+                parse_leaf(line).ok_or_else(|| syntax("leaf rows must fit in 8x8 cells of `.` and `*`"))?
+            } else {
+                let fields = line.split_whitespace()
+                    .map(str::parse::<usize>)
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|_| syntax("expected a node of the form `level nw ne sw se`"))?;
+                let [level, nw, ne, sw, se] = fields[..] else {
+                    return Err(syntax("expected a node of the form `level nw ne sw se`"));
+                };
+                if !(4..usize::BITS as usize).contains(&level) {
+                    return Err(syntax("non-leaf node levels must be at least 4"));
+                }
+                let children = [nw, ne, sw, se];
+                // nodes are 1-indexed and may only refer to earlier nodes one level down
+                for &child in &children {
+                    if child > nodes.len() || (child != 0 && nodes[child - 1].level() != level as u32 - 1) {
+                        return Err(syntax("child must be an earlier node one level below"));
+                    }
+                }
+                Node::Branch { level: level as u32, children }
+            };
+            nodes.push(node);
+        }
+
+        let root = nodes.len();
+        if root == 0 {
+            return Err(ParseError::Empty);
+        }
+        let mut live = vec![];
+        collect_live(&nodes, root, 0, 0, &mut live);
+
+        let Some(top) = live.iter().map(|&(row, _)| row).min() else {
+            return Ok(Board::dead(0, 0));
+        };
+        let left = live.iter().map(|&(_, col)| col).min().unwrap_or(0);
+        let height = live.iter().map(|&(row, _)| row).max().unwrap_or(0) - top + 1;
+        let width = live.iter().map(|&(_, col)| col).max().unwrap_or(0) - left + 1;
+
+        let mut board = Board::dead(height, width);
+        for (row, col) in live {
+            board.set(row - top, col - left, true);
+        }
+        Ok(board)
+    }
+}
+
+fn parse_leaf(line: &str) -> Option<Node> {
+    let mut rows = [0u8; 8];
+    let (mut row, mut col) = (0, 0);
+    for c in line.chars() {
+        if c == '$' {
+            row += 1;
+            col = 0;
+            continue;
+        }
+        if row >= 8 || col >= 8 {
+            return None;
+        }
+        match c {
+            '*' => rows[row] |= 1 << col,
+            '.' => {}
+            _ => return None,
+        }
+        col += 1;
+    }
+    Some(Node::Leaf(rows))
+}
+
+/// Pushes the absolute coordinates of every live cell under the 1-indexed node `index`, whose
+/// top-left corner is at `(top, left)`.
+fn collect_live(nodes: &[Node], index: usize, top: usize, left: usize, live: &mut Vec<(usize, usize)>) {
+    if index == 0 {
+        return;
+    }
+    match &nodes[index - 1] {
+        Node::Leaf(rows) => {
+            for (r, bits) in rows.iter().enumerate() {
+                live.extend((0..8).filter(|c| bits & (1 << c) != 0).map(|c| (top + r, left + c)));
+            }
+        }
+        Node::Branch { level, children } => {
+            let half = 1 << (level - 1);
+            let offsets = [(0, 0), (0, half), (half, 0), (half, half)];
+            for (&child, (dr, dc)) in children.iter().zip(offsets) {
+                collect_live(nodes, child, top + dr, left + dc, live);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn loads_block_from_macrocell() {
+        // the block straddles the bottom of the north-east leaf and the top of the south-east one
+        let input = "[M2] (golly 4.0)\n#R B3/S23\n$$$$$$$**$\n**$\n4 0 1 0 2\n";
+        let board = Board::from_macrocell(input).unwrap();
+
+        assert_eq!((2, 2), (board.rows(), board.cols()));
+        assert_eq!(4, board.population());
+        assert!(board.is_still_life());
+    }
+
+    #[test]
+    fn rejects_malformed_macrocell() {
+        assert_eq!(Err(ParseError::Empty), Board::from_macrocell("[M2] (golly 4.0)\n#R B3/S23\n"));
+        assert!(matches!(
+            Board::from_macrocell("[M2]\n**$\n5 1 0 0 0\n"),
+            Err(ParseError::Syntax { line: 3, .. })
+        ));
+        assert!(matches!(Board::from_macrocell("*********\n"), Err(ParseError::Syntax { line: 1, .. })));
+    }
+}
@@ -1,22 +1,178 @@
+use std::collections::HashMap;
 use std::fmt::Display;
+use std::time::Duration;
 
+use clap::Parser;
 use grid::Grid;
 use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+mod binary;
+mod draw;
+#[cfg(feature = "png")]
+mod frames;
+mod hex;
+mod interactive;
+#[cfg(feature = "json")]
+mod json;
+mod library;
+mod macrocell;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod replay;
+mod rle;
+mod rule;
+mod scene;
+mod second_order;
+mod simulation;
+mod sparse;
+mod text;
+mod timed;
+
+pub use binary::BoardState;
+pub use hex::HexBoard;
+#[cfg(feature = "rayon")]
+pub use parallel::simulate_many;
+pub use replay::Replay;
+pub use rle::{parse_rle_header, RleError};
+pub use rule::{Rule, RuleError};
+pub use scene::Scene;
+pub use second_order::SecondOrderBoard;
+pub use simulation::Simulation;
+pub use sparse::SparseBoard;
+pub use timed::TimedSim;
+
+/// Runs Conway's Game of Life on a random board in the terminal.
+#[derive(Debug, Parser)]
+struct Cli {
+    /// Start paused, and step, run or randomize the board from the keyboard
+    #[arg(long)]
+    interactive: bool,
+}
+
+const ROWS: usize = 50;
+const COLS: usize = 100;
+const FRAME: Duration = Duration::from_millis(50);
+
+fn main() -> std::io::Result<()> {
+    let cli = Cli::parse();
+    let mut board = Board::random(ROWS, COLS);
+    if cli.interactive {
+        return interactive::run(board, FRAME);
+    }
 
-fn main() {
-    let mut board = Board::random(50, 100);
     print!("\x1b[2J\x1b[?25l");
     loop {
         print!("\x1b[;H");
         println!("{board}");
         board.advance();
-        std::thread::sleep(std::time::Duration::from_millis(50));
+        std::thread::sleep(FRAME);
+    }
+}
+
+/// `(row, col)` offsets of the 8 cells surrounding a cell.
+const NEIGHBOR_OFFSETS: [(isize, isize); 8] = [
+    (-1, -1), (-1, 0), (-1, 1),
+    ( 0, -1),          ( 0, 1),
+    ( 1, -1), ( 1, 0), ( 1, 1),
+];
+
+/// The most cells a board can have for [`Board::predecessors`] to search it. Rows are enumerated
+/// along the board's shorter side, so at this size no row is wider than 6 cells.
+pub const MAX_PREDECESSOR_CELLS: usize = 36;
+
+/// How neighbors beyond one edge of the board are treated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum EdgeMode {
+    /// Cells beyond the edge are always dead.
+    #[default]
+    Dead,
+    /// The edge wraps around to the opposite side of the board.
+    Toroidal,
+    /// Cells beyond the edge are always alive.
+    Alive,
+}
+
+/// Edge handling for each axis of the board. `horizontal` applies to the left and right edges,
+/// `vertical` to the top and bottom.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct EdgeModes {
+    pub horizontal: EdgeMode,
+    pub vertical: EdgeMode,
+}
+
+impl EdgeModes {
+    /// Uses the same mode on every edge.
+    pub fn uniform(mode: EdgeMode) -> Self {
+        EdgeModes { horizontal: mode, vertical: mode }
+    }
+
+    /// Wraps on both axes.
+    pub fn toroidal() -> Self {
+        EdgeModes::uniform(EdgeMode::Toroidal)
+    }
+
+    /// Wraps left to right, with dead cells above and below.
+    pub fn horizontal_cylinder() -> Self {
+        EdgeModes { horizontal: EdgeMode::Toroidal, vertical: EdgeMode::Dead }
+    }
+
+    /// Counts the live neighbors of `(row, col)` on a `rows` x `cols` grid under these edges,
+    /// looking up in-grid cells with `alive`.
+    fn count_live_neighbors<F: Fn(usize, usize) -> bool>(
+        self,
+        row: usize,
+        col: usize,
+        (rows, cols): (usize, usize),
+        alive: F,
+    ) -> usize {
+        // each axis resolves its own offset, so off-grid neighbors are dead, alive, or wrapped
+        // around to the far side depending on the edge they cross
+        // a neighbor past a dead edge on either axis is dead even if the other edge is alive
+        NEIGHBOR_OFFSETS.iter()
+            .filter(|&&(dr, dc)| {
+                let vertical = self.vertical.resolve(row, dr, rows);
+                let horizontal = self.horizontal.resolve(col, dc, cols);
+                match (vertical, horizontal) {
+                    (Resolved::Index(r), Resolved::Index(c)) => alive(r, c),
+                    (Resolved::Dead, _) | (_, Resolved::Dead) => false,
+                    (Resolved::Alive, _) | (_, Resolved::Alive) => true,
+                }
+            })
+            .count()
+    }
+}
+
+/// Where an offset from a cell lands along one axis.
+enum Resolved {
+    Index(usize),
+    Dead,
+    Alive,
+}
+
+impl EdgeMode {
+    /// Resolves `index + delta` along an axis of length `len`.
+    fn resolve(self, index: usize, delta: isize, len: usize) -> Resolved {
+        match index.checked_add_signed(delta) {
+            Some(i) if i < len => Resolved::Index(i),
+            _ => match self {
+                EdgeMode::Dead => Resolved::Dead,
+                EdgeMode::Toroidal => Resolved::Index((index as isize + delta).rem_euclid(len as isize) as usize),
+                EdgeMode::Alive => Resolved::Alive,
+            },
+        }
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Board {
     grid: Grid<bool>,
+    edges: EdgeModes,
+    rule: Rule,
+    // live neighbors of every cell, kept in step with `grid` by `set` or rebuilt by `recount`
+    counts: Grid<u8>,
 }
 
 impl Display for Board {
@@ -29,7 +185,7 @@ impl Display for Board {
             };
             write!(f, "{}", symbol).unwrap();
             if col == self.grid.cols() - 1 {
-                write!(f, "\n").unwrap();
+                writeln!(f).unwrap();
             }
         }
         Ok(())
@@ -37,190 +193,3549 @@ impl Display for Board {
 }
 
 impl Board {
+    /// Creates a board of `rows` x `cols` with every cell dead.
+    ///
+    /// If either dimension is zero the board is empty, with 0 rows and 0 columns.
     pub fn dead(rows: usize, cols: usize) -> Self {
-        Board { grid: Grid::init(rows, cols, false) }
+        Board::from_grid(Grid::init(rows, cols, false))
     }
 
     /// Creates a board of `rows` x `cols` with every cell initialized randomly.
     /// 
-    /// `rows * cols` must be less than `usize::MAX`
+    /// `rows * cols` must be less than `usize::MAX`. As with [`Board::dead`], a zero dimension
+    /// gives an empty 0x0 board.
     pub fn random(rows: usize, cols: usize) -> Self {
         let mut grid = Grid::new(rows, cols);
-        grid.fill_with(|| rand::random::<bool>());
-        Board { grid }
+        grid.fill_with(rand::random::<bool>);
+        Board::from_grid(grid)
     }
 
-    pub fn advance(&mut self) {
+    /// Creates a randomly filled board with at least `min` live cells, reproducibly from `seed`.
+    ///
+    /// Each cell starts alive with even odds, then randomly chosen dead cells are brought to life
+    /// until the population reaches `min`. Fails if `min` is more than the board can hold.
+    pub fn random_min_pop(rows: usize, cols: usize, min: usize, seed: u64) -> Result<Self, ShapeError> {
+        let capacity = rows * cols;
+        if min > capacity {
+            return Err(ShapeError::Capacity { capacity, requested: min });
+        }
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut board = Board::dead(rows, cols);
+        board.set_each(|_, _| rng.gen());
+
+        let missing = min.saturating_sub(board.population());
+        let mut dead = (0..rows).cartesian_product(0..cols)
+            .filter(|&(row, col)| !board.grid[row][col])
+            .collect_vec();
+        dead.shuffle(&mut rng);
+        for &(row, col) in &dead[..missing] {
+            board.set(row, col, true);
+        }
+        Ok(board)
+    }
+
+    /// Creates a board shaped like `weights`, where each cell starts alive with probability
+    /// `weights[row][col]`.
+    ///
+    /// Fails if the rows of `weights` differ in length or any weight is outside `0.0..=1.0`.
+    pub fn random_weighted<R: Rng>(rng: &mut R, weights: &[Vec<f64>]) -> Result<Board, ShapeError> {
+        let cols = weights.first().map_or(0, Vec::len);
+        if let Some((row, found)) = weights.iter().map(Vec::len).enumerate().find(|&(_, len)| len != cols) {
+            return Err(ShapeError::RowLength { row, expected: cols, found });
+        }
+        let cells = weights.iter().enumerate()
+            .flat_map(|(row, weights)| weights.iter().enumerate().map(move |(col, &weight)| (row, col, weight)))
+            .map(|(row, col, weight)| match weight {
+                p if (0.0..=1.0).contains(&p) => Ok(rng.gen_bool(p)),
+                _ => Err(ShapeError::Weight { row, col }),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Board::from_grid(build_grid(cells, cols)?))
+    }
+
+    /// Sets each cell in the `height` x `width` rectangle at `(row, col)` to alive with
+    /// probability `density`, leaving the rest of the board alone. The rectangle is clipped to
+    /// the board and `density` is clamped to `0.0..=1.0`.
+    pub fn randomize_region<R: Rng>(
+        &mut self,
+        rng: &mut R,
+        row: usize,
+        col: usize,
+        height: usize,
+        width: usize,
+        density: f64,
+    ) {
+        let density = density.clamp(0.0, 1.0);
+        let bottom = row.saturating_add(height).min(self.grid.rows());
+        let right = col.saturating_add(width).min(self.grid.cols());
+        for (r, c) in (row..bottom).cartesian_product(col..right) {
+            self.set(r, c, rng.gen_bool(density));
+        }
+    }
+
+    /// Flips each cell with probability `rate`, clamped to `0.0..=1.0`, so a rate of 1 inverts
+    /// the board.
+    pub fn mutate<R: Rng>(&mut self, rng: &mut R, rate: f64) {
+        let rate = rate.clamp(0.0, 1.0);
+        for (row, col) in (0..self.grid.rows()).cartesian_product(0..self.grid.cols()) {
+            if rng.gen_bool(rate) {
+                self.toggle(row, col);
+            }
+        }
+    }
+
+    /// Wraps a grid in a board with dead edges that follows Conway's rule.
+    fn from_grid(grid: Grid<bool>) -> Self {
+        Board::with_parts(grid, EdgeModes::default(), Rule::conway())
+    }
+
+    /// Creates a board with the same settings as this one but different cells.
+    fn with_grid(&self, grid: Grid<bool>) -> Self {
+        Board::with_parts(grid, self.edges, self.rule)
+    }
+
+    /// Like [`Board::with_grid`], for cells generated row by row from this board's shape or a
+    /// shape derived from it.
+    fn with_cells(&self, cells: Vec<bool>, cols: usize) -> Self {
+        self.with_grid(build_grid(cells, cols).expect("cells fill whole rows"))
+    }
+
+    fn with_parts(grid: Grid<bool>, edges: EdgeModes, rule: Rule) -> Self {
+        let counts = Grid::new(grid.rows(), grid.cols());
+        let mut board = Board { grid, edges, rule, counts };
+        board.recount();
+        board
+    }
+
+    /// Returns the board with its edge handling replaced.
+    pub fn with_edges(mut self, edges: EdgeModes) -> Self {
+        self.edges = edges;
+        self.recount();
+        self
+    }
+
+    /// Returns whether the cell at `(row, col)` is alive, or `None` if it's off the board.
+    pub fn get(&self, row: usize, col: usize) -> Option<bool> {
+        self.grid.get(row, col).copied()
+    }
+
+    /// Sets the cell at `(row, col)`, updating the cached counts of its neighbors rather than
+    /// recounting the whole board.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `(row, col)` is off the board.
+    pub fn set(&mut self, row: usize, col: usize, alive: bool) {
+        if self.grid[row][col] == alive {
+            return;
+        }
+        self.grid[row][col] = alive;
+        for &(dr, dc) in &NEIGHBOR_OFFSETS {
+            let vertical = self.edges.vertical.resolve(row, dr, self.grid.rows());
+            let horizontal = self.edges.horizontal.resolve(col, dc, self.grid.cols());
+            if let (Resolved::Index(r), Resolved::Index(c)) = (vertical, horizontal) {
+                let count = &mut self.counts[r][c];
+                *count = if alive { *count + 1 } else { *count - 1 };
+            }
+        }
+    }
+
+    /// Flips the cell at `(row, col)` between alive and dead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `(row, col)` is off the board.
+    pub fn toggle(&mut self, row: usize, col: usize) {
+        self.set(row, col, !self.grid[row][col]);
+    }
+
+    /// Sets the cell at `(row, col)` and every cell connected to it through up, down, left and
+    /// right steps over cells of the same state to `value`. The fill doesn't wrap around edges.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `(row, col)` is off the board.
+    pub fn flood_fill(&mut self, row: usize, col: usize, value: bool) {
+        let target = self.grid[row][col];
+        if target == value {
+            return;
+        }
         let (rows, cols) = (self.grid.rows(), self.grid.cols());
-        let new_state = (0..rows).cartesian_product(0..cols)
-            .map(|(row, col)| match &self.count_live_neighbors(row, col) {
-                0..=1 => false,                 // if alive, becomes dead; if dead, stays dead
-                2 => *(&self.grid[row][col]),   // unchanged whether originally alive or dead
-                3 => true,                      // if alive, stays alive; if dead, becomes alive
-                _ => false,                     // more than 3 live neighbors becomes dead
+        let mut stack = vec![(row, col)];
+        while let Some((r, c)) = stack.pop() {
+            if self.grid[r][c] != target {
+                continue;
+            }
+            self.set(r, c, value);
+            // wrapping_sub sends the off-board neighbors of row or column 0 out of range
+            for (nr, nc) in [(r.wrapping_sub(1), c), (r + 1, c), (r, c.wrapping_sub(1)), (r, c + 1)] {
+                if nr < rows && nc < cols && self.grid[nr][nc] == target {
+                    stack.push((nr, nc));
+                }
+            }
+        }
+    }
+
+    /// Counts the live neighbors of every cell from scratch, in row-major order.
+    pub fn neighbor_counts(&self) -> Vec<u8> {
+        (0..self.grid.rows()).cartesian_product(0..self.grid.cols())
+            .map(|(row, col)| self.count_live_neighbors(row, col) as u8)
+            .collect()
+    }
+
+    /// Like [`Board::neighbor_counts`], but sums each cell's row of three first and then adds up
+    /// three of those row sums, subtracting the cell itself. Edges are handled the same way, so
+    /// the results always agree.
+    pub fn neighbor_counts_conv(&self) -> Vec<u8> {
+        let (rows, cols) = (self.grid.rows(), self.grid.cols());
+        let horizontal = |col: usize, dc: isize| self.edges.horizontal.resolve(col, dc, cols);
+
+        let row_sums = (0..rows).cartesian_product(0..cols)
+            .map(|(row, col)| {
+                (-1..=1).map(|dc| match horizontal(col, dc) {
+                    Resolved::Index(c) => u8::from(self.grid[row][c]),
+                    Resolved::Dead => 0,
+                    Resolved::Alive => 1,
+                }).sum::<u8>()
             })
             .collect_vec();
-        
-        self.grid = Grid::from_vec(new_state, cols);
+        (0..rows).cartesian_product(0..cols)
+            .map(|(row, col)| {
+                let block = (-1..=1).map(|dr| match self.edges.vertical.resolve(row, dr, rows) {
+                    Resolved::Index(r) => row_sums[r * cols + col],
+                    Resolved::Dead => 0,
+                    // a row past an alive edge is alive except where it also crosses a dead one
+                    Resolved::Alive => (-1..=1)
+                        .filter(|&dc| !matches!(horizontal(col, dc), Resolved::Dead))
+                        .count() as u8,
+                }).sum::<u8>();
+                block - u8::from(self.grid[row][col])
+            })
+            .collect()
     }
 
-    fn count_live_neighbors(&self, row: usize, col: usize) -> usize {
-        // literally just binding to reduce the times writing self.grid
-        let grid = &self.grid;
+    fn recount(&mut self) {
+        self.counts = build_grid(self.neighbor_counts(), self.grid.cols())
+            .expect("there's one count per cell");
+    }
 
-        // finds valid indices around the given coordinates
-        // might be worth breaking off as a config option if implementing toroidal board space
-        let valid_rows = match row {
-            0 => 0..=1usize,
-            r if r == grid.rows() - 1 => (r-1)..=r,
-            _ => (row - 1)..=(row + 1),
-        };
-        let valid_cols = match col {
-            0 => 0..=1usize,
-            r if r == grid.cols() - 1 => (r-1)..=r,
-            _ => (col - 1)..=(col + 1),
-        };
+    pub fn edges(&self) -> EdgeModes {
+        self.edges
+    }
 
-        valid_rows.cartesian_product(valid_cols)
-            .filter(|i| i != &(row, col))   // make sure we're ignoring the cell @ (row, col) itself
-            .filter(|&(r, c)| grid[r][c])   // could have combined the two filters but eh
-            .count()
+    /// Returns the board with the rule it advances by replaced. Boards follow Conway's rule
+    /// unless given another.
+    pub fn with_rule(mut self, rule: Rule) -> Self {
+        self.rule = rule;
+        self
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    pub fn rule(&self) -> Rule {
+        self.rule
+    }
 
-    fn create_board_x_by_x<T: as_bool::AsBool>(vec: Vec<T>, x: usize) -> Board {
-        let vec = vec.iter().map(|i| i.as_bool()).collect();
-        Board { grid: Grid::from_vec(vec, x) }
+    pub fn rows(&self) -> usize {
+        self.grid.rows()
     }
 
-    #[test]
-    fn dead_0_neighbors_stay_dead() {
-        let mut board = create_board_x_by_x(vec![0;9], 3);
-        let expected = board.clone();
-        board.advance();
-        assert_eq!(expected, board);
+    pub fn cols(&self) -> usize {
+        self.grid.cols()
     }
 
-    #[test]
-    fn dead_3_neighbors_come_alive() {
-        let mut board = create_board_x_by_x(vec![
-            0, 0, 1,
-            0, 1, 1,
-            0, 0, 0,
-        ], 3);
+    /// Sets every cell to the result of calling `f(row, col)`, in row-major order.
+    pub fn set_each<F: FnMut(usize, usize) -> bool>(&mut self, mut f: F) {
+        for (row, col) in (0..self.grid.rows()).cartesian_product(0..self.grid.cols()) {
+            self.grid[row][col] = f(row, col);
+        }
+        self.recount();
+    }
 
-        let expected = create_board_x_by_x(vec![
-            0, 1, 1,
-            0, 1, 1,
-            0, 0, 0,
-        ], 3);
+    /// Advances the board one generation. An empty board stays empty.
+    pub fn advance(&mut self) {
+        *self = self.next_generation();
+    }
 
-        board.advance();
-        assert_eq!(expected, board);
+    /// Advances the board `n` generations.
+    pub fn advance_n(&mut self, n: usize) {
+        for _ in 0..n {
+            self.advance();
+        }
     }
 
-    #[test]
-    fn alive_4_neighbors_die() {
-        let mut board = create_board_x_by_x(vec![
-            0, 1, 1,
-            0, 1, 1,
-            0, 0, 1,
-        ], 3);
+    /// Returns generation `n` of the board, leaving `self` alone. The same as cloning and calling
+    /// [`Board::advance_n`].
+    pub fn phase(&self, n: usize) -> Board {
+        let mut board = self.clone();
+        board.advance_n(n);
+        board
+    }
 
-        let expected = create_board_x_by_x(vec![
-            0, 1, 1,
-            0, 0, 0,
-            0, 1, 1,
-        ], 3);
+    /// Advances the board up to `steps` generations, stopping early if the population grows past
+    /// `max_pop`.
+    ///
+    /// Returns `Ok(steps)` if the cap was never exceeded, or `Err(step)` with the generation at
+    /// which it first was, leaving the board in that state. A board already over the cap returns
+    /// `Err(0)` without advancing.
+    pub fn advance_capped(&mut self, steps: usize, max_pop: usize) -> Result<usize, usize> {
+        for step in 0..steps {
+            if self.population() > max_pop {
+                return Err(step);
+            }
+            self.advance();
+        }
+        if self.population() > max_pop {
+            return Err(steps);
+        }
+        Ok(steps)
+    }
 
-        board.advance();
-        assert_eq!(expected, board);
+    /// Advances the board one generation, first growing it with dead cells as needed so that no
+    /// live cell is within `margin` cells of any edge. The board never shrinks.
+    pub fn advance_growing(&mut self, margin: usize) {
+        if let Some((top, left, height, width)) = self.bounding_box() {
+            let bottom = self.grid.rows() - (top + height);
+            let right = self.grid.cols() - (left + width);
+            *self = self.pad(
+                margin.saturating_sub(top),
+                margin.saturating_sub(left),
+                margin.saturating_sub(bottom),
+                margin.saturating_sub(right),
+            );
+        }
+        self.advance();
     }
 
-    #[test]
-    fn common_still_lifes() {
-        let still_lifes = vec![
-            // block
-            create_board_x_by_x(vec![
-                0, 0, 0, 0,
-                0, 1, 1, 0,
-                0, 1, 1, 0,
-                0, 0, 0, 0,
-            ], 4),
-            
-            // beehive
-            create_board_x_by_x(vec![
-                0, 0, 0, 0, 0, 0,
-                0, 0, 1, 1, 0, 0,
-                0, 1, 0, 0, 1, 0,
-                0, 0, 1, 1, 0, 0,
-                0, 0, 0, 0, 0, 0,
-                0, 0, 0, 0, 0, 0,
-            ], 6),
+    /// Returns the next generation of the board without modifying it.
+    pub fn next_generation(&self) -> Board {
+        let (rows, cols) = (self.grid.rows(), self.grid.cols());
+        let new_state = (0..rows).cartesian_product(0..cols)
+            .map(|(row, col)| self.next_cell(row, col))
+            .collect_vec();
+        
+        self.with_cells(new_state, cols)
+    }
 
-            // boat
-            create_board_x_by_x(vec![
-                0, 0, 0, 0, 0, 
-                0, 0, 1, 0, 0, 
-                0, 1, 0, 1, 0, 
-                0, 1, 1, 0, 0, 
-                0, 0, 0, 0, 0, 
-            ], 5),
+    /// Writes the next generation into `out` in row-major order, without allocating. `out` must
+    /// hold exactly `rows * cols` cells.
+    pub fn advance_into(&self, out: &mut [bool]) -> Result<(), ShapeError> {
+        let (rows, cols) = (self.grid.rows(), self.grid.cols());
+        if out.len() != rows * cols {
+            return Err(ShapeError::Length { expected: rows * cols, found: out.len() });
+        }
+        for (cell, (row, col)) in out.iter_mut().zip((0..rows).cartesian_product(0..cols)) {
+            *cell = self.next_cell(row, col);
+        }
+        Ok(())
+    }
 
-        ];
+    /// Returns the state of the cell at `(row, col)` in the next generation.
+    fn next_cell(&self, row: usize, col: usize) -> bool {
+        self.rule.next_state(self.grid[row][col], usize::from(self.counts[row][col]))
+    }
 
-        for mut board in still_lifes {
-            let expected = board.clone();
-    
+    /// Wraps the board into a torus and returns the first number of steps, up to `max_steps`,
+    /// after which it's back in exactly its starting state, or `None` if it isn't.
+    ///
+    /// Translation counts: a spaceship has to travel all the way around before it returns, so a
+    /// glider on an `n` x `n` torus takes `4 * n` steps. Patterns that never revisit their
+    /// starting state, like most soups, return `None`.
+    pub fn toroidal_return_period(&self, max_steps: usize) -> Option<usize> {
+        let start = self.clone().with_edges(EdgeModes::toroidal());
+        let mut board = start.clone();
+        for step in 1..=max_steps {
             board.advance();
-            assert_eq!(expected, board);
+            if board.grid == start.grid {
+                return Some(step);
+            }
         }
+        None
     }
 
-    #[test]
-    fn simple_oscillators() {
-        let mut blinker = create_board_x_by_x(vec![
-            0, 0, 0, 0, 0, 
-            0, 0, 1, 0, 0, 
-            0, 0, 1, 0, 0, 
-            0, 0, 1, 0, 0, 
-            0, 0, 0, 0, 0, 
-        ], 5);
+    /// Checks that `period` is exactly the board's minimal period: advancing it `period` times
+    /// returns to the starting state, and no fewer steps do.
+    ///
+    /// Still lifes pass with a period of 1. A period of 0 never passes.
+    pub fn verify_oscillator(&self, period: usize) -> bool {
+        let mut board = self.clone();
+        for step in 1..=period {
+            board.advance();
+            if board == *self {
+                return step == period;
+            }
+        }
+        false
+    }
 
-        let expected = create_board_x_by_x(vec![
-            0, 0, 0, 0, 0, 
-            0, 0, 0, 0, 0, 
-            0, 1, 1, 1, 0, 
-            0, 0, 0, 0, 0, 
-            0, 0, 0, 0, 0, 
-        ], 5);
+    /// Advances the board one step, with each cell following the rule of the first region in
+    /// `regions` that contains it, or `default` if none do.
+    ///
+    /// Regions may overlap and needn't cover the board. Neighbor counts still look across region
+    /// boundaries.
+    pub fn advance_regional(&mut self, regions: &[(Rect, Rule)], default: &Rule) {
+        let (rows, cols) = (self.grid.rows(), self.grid.cols());
+        let new_state = (0..rows).cartesian_product(0..cols)
+            .map(|(row, col)| {
+                let rule = regions.iter()
+                    .find(|(rect, _)| rect.contains(row, col))
+                    .map_or(default, |(_, rule)| rule);
+                rule.next_state(self.grid[row][col], usize::from(self.counts[row][col]))
+            })
+            .collect_vec();
 
-        blinker.advance();
-        assert_eq!(expected, blinker);
+        *self = self.with_cells(new_state, cols);
+    }
 
-        let mut toad = create_board_x_by_x(vec![
-            0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0,
-            0, 0, 1, 1, 1, 0,
-            0, 1, 1, 1, 0, 0,
-            0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0,
-        ], 6);
-        
-        let expected = create_board_x_by_x(vec![
-            0, 0, 0, 0, 0, 0,
-            0, 0, 0, 1, 0, 0,
-            0, 1, 0, 0, 1, 0,
-            0, 1, 0, 0, 1, 0,
-            0, 0, 1, 0, 0, 0,
-            0, 0, 0, 0, 0, 0,
-        ], 6);
+    /// Finds the smallest period, up to `max_period`, after which the pattern reappears
+    /// unchanged apart from a shift, returning `(drow, dcol, period)`.
+    ///
+    /// Spaceships report their displacement per period, while still lifes and oscillators
+    /// report a displacement of `(0, 0)`. Returns `None` for a dead board or if the pattern
+    /// doesn't recur, which includes spaceships hitting the edge of the board.
+    pub fn measure_velocity(&self, max_period: usize) -> Option<(i64, i64, usize)> {
+        let (top, left, _, _) = self.bounding_box()?;
+        let shape = self.trim();
 
-        toad.advance();
-        assert_eq!(expected, toad);
+        let mut board = self.clone();
+        for period in 1..=max_period {
+            board.advance();
+            let (new_top, new_left, _, _) = board.bounding_box()?;
+            if board.trim() == shape {
+                return Some((new_top as i64 - top as i64, new_left as i64 - left as i64, period));
+            }
+        }
+        None
+    }
+
+    /// Searches for a board that evolves into `target` after `steps` generations, by hill
+    /// climbing from a random start for up to `iterations` single-cell mutations.
+    ///
+    /// A mutation is kept unless it moves the evolved board further from `target` in hamming
+    /// distance, and the search restarts from a fresh random board whenever it goes a while
+    /// without improving. Returns the first board found that reaches `target` exactly, or `None`
+    /// if the search runs out of iterations first. Results are reproducible for a given `seed`.
+    pub fn search_predecessor(target: &Board, steps: usize, iterations: usize, seed: u64) -> Option<Board> {
+        let (rows, cols) = (target.grid.rows(), target.grid.cols());
+        let mut rng = StdRng::seed_from_u64(seed);
+        let distance = |board: &Board| {
+            let mut board = board.clone();
+            board.advance_n(steps);
+            board.hamming_distance(target).expect("evolving keeps the board's size")
+        };
+        // enough mutations to try flipping most cells a few times over
+        let patience = 4 * rows * cols;
+
+        let mut start = target.clone();
+        let (mut best, mut stale) = (usize::MAX, patience);
+        for _ in 0..iterations {
+            if best == 0 {
+                return Some(start);
+            }
+            if rows == 0 {
+                break;
+            }
+            if stale >= patience {
+                start.set_each(|_, _| rng.gen());
+                (best, stale) = (distance(&start), 0);
+                continue;
+            }
+
+            let (row, col) = (rng.gen_range(0..rows), rng.gen_range(0..cols));
+            start.toggle(row, col);
+            match distance(&start) {
+                d if d < best => (best, stale) = (d, 0),
+                d if d == best => stale += 1,
+                _ => {
+                    start.toggle(row, col);
+                    stale += 1;
+                }
+            }
+        }
+        (best == 0).then_some(start)
+    }
+
+    /// Finds up to `limit` boards of the same size and edge settings whose next generation is
+    /// this board.
+    ///
+    /// This is an exhaustive backtracking search, assigning one row of the candidate at a time
+    /// and pruning as soon as a finished row disagrees with `self`. A board wider than it is tall
+    /// is searched transposed, so the rows being enumerated are as short as possible. The work
+    /// still grows exponentially with the board's size, so boards of more than
+    /// [`MAX_PREDECESSOR_CELLS`] cells are refused with an error.
+    pub fn predecessors(&self, limit: usize) -> Result<Vec<Board>, ShapeError> {
+        let cells = self.grid.rows() * self.grid.cols();
+        if cells > MAX_PREDECESSOR_CELLS {
+            return Err(ShapeError::SearchSize { limit: MAX_PREDECESSOR_CELLS, cells });
+        }
+        if self.grid.cols() > self.grid.rows() {
+            return Ok(self.transpose().predecessors(limit)?.iter().map(Board::transpose).collect());
+        }
+        let mut found = vec![];
+        if limit > 0 {
+            let mut candidate = self.with_grid(Grid::init(self.grid.rows(), self.grid.cols(), false));
+            self.search_predecessors(&mut candidate, 0, limit, &mut found);
+        }
+        Ok(found)
+    }
+
+    /// Reflects the board across its main diagonal, swapping its edge modes to match, so the
+    /// transposed board evolves as the transpose of this one.
+    fn transpose(&self) -> Board {
+        let edges = EdgeModes { horizontal: self.edges.vertical, vertical: self.edges.horizontal };
+        self.transform(Transform::FlipDiagonal).with_edges(edges)
+    }
+
+    fn search_predecessors(&self, candidate: &mut Board, row: usize, limit: usize, found: &mut Vec<Board>) {
+        let (rows, cols) = (self.grid.rows(), self.grid.cols());
+        let row_matches = |candidate: &Board, r: usize| {
+            (0..cols).all(|c| candidate.next_cell(r, c) == self.grid[r][c])
+        };
+        // with wrapping rows, the first row's neighbors aren't known until the last is assigned
+        let first_checkable = usize::from(self.edges.vertical == EdgeMode::Toroidal);
+
+        if row == rows {
+            if (0..rows).all(|r| row_matches(candidate, r)) {
+                found.push(candidate.clone());
+            }
+            return;
+        }
+        let Some(end) = u32::try_from(cols).ok().and_then(|cols| 1u64.checked_shl(cols)) else {
+            return;
+        };
+        for bits in 0..end {
+            for col in 0..cols {
+                candidate.set(row, col, bits & (1 << col) != 0);
+            }
+            // every neighbor of the previous row has now been assigned
+            if row > first_checkable && !row_matches(candidate, row - 1) {
+                continue;
+            }
+            self.search_predecessors(candidate, row + 1, limit, found);
+            if found.len() >= limit {
+                break;
+            }
+        }
+        (0..cols).for_each(|col| candidate.set(row, col, false));
+    }
+
+    /// Returns whether the board is a Garden of Eden: a state with no predecessor, which can
+    /// only ever appear as a starting pattern.
+    ///
+    /// Predecessors are searched with [`Board::predecessors`] on a board of the same size and
+    /// edges, so the same size limit applies and larger boards give an error. The answer is about
+    /// this bounded board rather than the infinite plane.
+    pub fn is_garden_of_eden(&self) -> Result<bool, ShapeError> {
+        Ok(self.predecessors(1)?.is_empty())
+    }
+
+    /// Returns whether the board is unchanged by advancing it one generation.
+    pub fn is_still_life(&self) -> bool {
+        self.next_generation() == *self
+    }
+
+    /// Returns whether no cell will change on the next step. This is exactly
+    /// [`Board::is_still_life`], named for code that schedules work around idle boards.
+    pub fn is_quiescent(&self) -> bool {
+        self.is_still_life()
+    }
+
+    /// Returns the smallest period greater than 1, up to `max_period`, after which the board
+    /// returns to its current state.
+    ///
+    /// Still lifes (period 1) are not oscillators and return `None`.
+    pub fn is_oscillator(&self, max_period: usize) -> Option<usize> {
+        let mut board = self.next_generation();
+        if board == *self {
+            return None;
+        }
+        for period in 2..=max_period {
+            board.advance();
+            if board == *self {
+                return Some(period);
+            }
+        }
+        None
+    }
+
+    /// Advances the board one step under a totalistic rule, where a cell's next state depends
+    /// only on the live count of the full 3x3 block centered on it (see [`Board::count_block`]).
+    pub fn advance_totalistic<F: Fn(usize) -> bool>(&mut self, rule: F) {
+        let (rows, cols) = (self.grid.rows(), self.grid.cols());
+        let new_state = (0..rows).cartesian_product(0..cols)
+            .map(|(row, col)| rule(self.count_block(row, col)))
+            .collect_vec();
+
+        *self = self.with_cells(new_state, cols);
+    }
+
+    /// Advances the board `steps` times, calling `on_step` with the new state and its generation
+    /// number (starting at 1) after each step.
+    pub fn run<F: FnMut(&Board, u64)>(&mut self, steps: usize, mut on_step: F) {
+        for generation in 1..=steps as u64 {
+            self.advance();
+            on_step(self, generation);
+        }
+    }
+
+    /// Returns the board followed by each of its next `steps` generations, leaving `self`
+    /// untouched. The result always has `steps + 1` boards.
+    pub fn trace(&self, steps: usize) -> Vec<Board> {
+        let mut boards = Vec::with_capacity(steps + 1);
+        boards.push(self.clone());
+        for _ in 0..steps {
+            let next = boards[boards.len() - 1].next_generation();
+            boards.push(next);
+        }
+        boards
+    }
+
+    /// Advances a clone of the board `steps` times, returning for each step the cells it
+    /// changed as `(row, col, alive)`, in row-major order. Setting each step's cells in turn
+    /// replays the run from the starting board.
+    pub fn delta_stream(&self, steps: usize) -> Vec<Vec<(usize, usize, bool)>> {
+        let mut board = self.clone();
+        (0..steps)
+            .map(|_| {
+                let next = board.next_generation();
+                let changes = (0..board.grid.rows()).cartesian_product(0..board.grid.cols())
+                    .filter(|&(row, col)| board.grid[row][col] != next.grid[row][col])
+                    .map(|(row, col)| (row, col, next.grid[row][col]))
+                    .collect();
+                board = next;
+                changes
+            })
+            .collect()
+    }
+
+    /// Like [`Board::trace`], but keeps only the starting board and every `interval`th
+    /// generation after it, up to generation `steps`. An interval of 0 keeps just the start.
+    pub fn run_sampled(&self, steps: usize, interval: usize) -> Vec<Board> {
+        let mut board = self.clone();
+        let mut samples = vec![board.clone()];
+        if interval == 0 {
+            return samples;
+        }
+        for step in 1..=steps {
+            board.advance();
+            if step % interval == 0 {
+                samples.push(board.clone());
+            }
+        }
+        samples
+    }
+
+    /// Returns the average change in population per step over the next `steps` steps, which is
+    /// the mean of [`Board::population_deltas`]. No steps give 0.
+    pub fn growth_rate(&self, steps: usize) -> f64 {
+        if steps == 0 {
+            return 0.0;
+        }
+        self.population_deltas(steps).iter().sum::<i64>() as f64 / steps as f64
+    }
+
+    /// Returns the average `(row, col)` change per step in the centroid of the live cells over
+    /// the next `steps` steps, so a south-east glider drifts `(0.25, 0.25)` and a still life
+    /// `(0.0, 0.0)`.
+    ///
+    /// Steps to or from a dead board have no centroid to compare and are left out of the
+    /// average. With no steps left the drift is `(0.0, 0.0)`.
+    pub fn activity_drift(&self, steps: usize) -> (f64, f64) {
+        let centroids = self.trace(steps).iter().map(Board::centroid).collect_vec();
+        let moves = centroids.into_iter().tuple_windows()
+            .filter_map(|(before, after)| {
+                let ((r0, c0), (r1, c1)) = (before?, after?);
+                Some((r1 - r0, c1 - c0))
+            })
+            .collect_vec();
+        if moves.is_empty() {
+            return (0.0, 0.0);
+        }
+        let (rows, cols) = moves.iter().fold((0.0, 0.0), |(rows, cols), (dr, dc)| (rows + dr, cols + dc));
+        (rows / moves.len() as f64, cols / moves.len() as f64)
+    }
+
+    /// The mean `(row, col)` of the live cells, or `None` if there are none.
+    fn centroid(&self) -> Option<(f64, f64)> {
+        let population = self.population();
+        if population == 0 {
+            return None;
+        }
+        let (rows, cols) = self.live_cells().fold((0, 0), |(rows, cols), (row, col)| (rows + row, cols + col));
+        Some((rows as f64 / population as f64, cols as f64 / population as f64))
+    }
+
+    /// Advances a clone of the board `steps` times, returning the signed change in population
+    /// made by each step.
+    pub fn population_deltas(&self, steps: usize) -> Vec<i64> {
+        let mut board = self.clone();
+        (0..steps)
+            .map(|_| {
+                let before = board.population() as i64;
+                board.advance();
+                board.population() as i64 - before
+            })
+            .collect()
+    }
+
+    /// Advances a clone of the board `steps` times, returning for each step how many cells
+    /// changed at least once during it or the `window - 1` steps before it.
+    ///
+    /// A window of 1 gives the raw number of changes per step, and a window of 0 gives all zeros.
+    pub fn windowed_activity(&self, steps: usize, window: usize) -> Vec<usize> {
+        let mut board = self.clone();
+        // the most recent step each cell changed in, counting from 1
+        let mut last_change = vec![0; self.grid.rows() * self.grid.cols()];
+        (1..=steps)
+            .map(|step| {
+                let next = board.next_generation();
+                for (last, (before, after)) in last_change.iter_mut().zip(board.grid.iter().zip(next.grid.iter())) {
+                    if before != after {
+                        *last = step;
+                    }
+                }
+                board = next;
+                last_change.iter().filter(|&&last| last > 0 && last + window > step).count()
+            })
+            .collect()
+    }
+
+    /// Folds `f` over the board and each of its next `steps` generations, in order, without
+    /// modifying `self`.
+    pub fn fold_generations<B, F: FnMut(B, &Board) -> B>(&self, steps: usize, init: B, mut f: F) -> B {
+        let mut board = self.clone();
+        let mut acc = f(init, &board);
+        for _ in 0..steps {
+            board.advance();
+            acc = f(acc, &board);
+        }
+        acc
+    }
+
+    /// Counts how many of the board and its next `steps` generations each cell is alive in,
+    /// without modifying `self`. The result is indexed `[row][col]`.
+    pub fn heatmap(&self, steps: usize) -> Vec<Vec<u32>> {
+        let empty = vec![vec![0; self.grid.cols()]; self.grid.rows()];
+        self.fold_generations(steps, empty, |mut heat, board| {
+            for (row, col) in board.live_cells() {
+                heat[row][col] += 1;
+            }
+            heat
+        })
+    }
+
+    /// Returns the state of the cell at `(row, col)` on the board and each of its next `steps`
+    /// generations, without modifying `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `(row, col)` is off the board.
+    pub fn cell_history(&self, row: usize, col: usize, steps: usize) -> Vec<bool> {
+        self.fold_generations(steps, vec![], |mut history, board| {
+            history.push(board.grid[row][col]);
+            history
+        })
+    }
+
+    /// Returns a board marking the cells that keep the same state through the board's next
+    /// `steps` generations, whether they stay alive or stay dead.
+    pub fn stable_cells(&self, steps: usize) -> Board {
+        let (rows, cols) = (self.grid.rows(), self.grid.cols());
+        let changed = self.fold_generations(steps, vec![false; rows * cols], |mut changed, board| {
+            for (changed, (now, start)) in changed.iter_mut().zip(board.grid.iter().zip(self.grid.iter())) {
+                *changed |= now != start;
+            }
+            changed
+        });
+        let mut stable = self.with_grid(Grid::new(rows, cols));
+        stable.set_each(|row, col| !changed[row * cols + col]);
+        stable
+    }
+
+    /// Returns how many consecutive generations each cell has been alive for after `steps` more
+    /// generations, counting the starting board as one. Cells born on the last step have age 1
+    /// and dead cells 0. The result is indexed `[row][col]`.
+    pub fn age_grid(&self, steps: usize) -> Vec<Vec<u32>> {
+        let empty = vec![vec![0; self.grid.cols()]; self.grid.rows()];
+        self.fold_generations(steps, empty, |mut ages, board| {
+            for (row, col) in (0..board.grid.rows()).cartesian_product(0..board.grid.cols()) {
+                ages[row][col] = if board.grid[row][col] { ages[row][col] + 1 } else { 0 };
+            }
+            ages
+        })
+    }
+
+    /// Returns how many steps it takes the population to reach at least `target`, or `None` if
+    /// it doesn't within `max_steps`. A board already at `target` takes 0 steps.
+    pub fn steps_to_population(&self, target: usize, max_steps: usize) -> Option<usize> {
+        let mut board = self.clone();
+        for step in 0..=max_steps {
+            if board.population() >= target {
+                return Some(step);
+            }
+            board.advance();
+        }
+        None
+    }
+
+    /// Returns how many steps it takes the cell at `(row, col)` to first come alive, or `None`
+    /// if it doesn't within `max_steps` or is off the board. A cell alive now takes 0 steps.
+    pub fn time_to_activate(&self, row: usize, col: usize, max_steps: usize) -> Option<usize> {
+        let mut board = self.clone();
+        for step in 0..=max_steps {
+            if board.get(row, col)? {
+                return Some(step);
+            }
+            board.advance();
+        }
+        None
+    }
+
+    /// Advances a copy of the board until it reaches a state it will keep returning to, giving
+    /// that state and the generation it first appears in, or `None` if no state repeats within
+    /// `max_steps` steps.
+    ///
+    /// A pattern that settles into a still life gives the still life, and one that settles
+    /// into an oscillator gives the first phase of the cycle it reaches.
+    pub fn settle(&self, max_steps: usize) -> Option<(Board, usize)> {
+        let mut history = vec![self.clone()];
+        let mut seen: HashMap<u64, Vec<usize>> = HashMap::from([(self.checksum(), vec![0])]);
+        for _ in 0..max_steps {
+            let next = history.last().expect("history starts with the board").next_generation();
+            let matches = seen.entry(next.checksum()).or_default();
+            if let Some(&start) = matches.iter().find(|&&gen| history[gen].grid == next.grid) {
+                return Some((next, start));
+            }
+            matches.push(history.len());
+            history.push(next);
+        }
+        None
+    }
+
+    /// Gives a quick verdict on how the board's evolution ends within `max_steps` steps: dying
+    /// out, settling into a still life or oscillator, or still changing.
+    ///
+    /// Dying out takes precedence, so a board that ends empty is [`Lifespan::Extinct`] rather
+    /// than a period 1 [`Lifespan::Stabilized`].
+    pub fn classify(&self, max_steps: usize) -> Lifespan {
+        let Some((state, step)) = self.settle(max_steps) else {
+            return Lifespan::Active;
+        };
+        if state.population() == 0 {
+            return Lifespan::Extinct(step);
+        }
+        let period = state.is_oscillator(max_steps).unwrap_or(1);
+        Lifespan::Stabilized { step, period }
+    }
+
+    /// Advances a copy of the board up to `max_steps` times, returning `(first_seen, repeat)`
+    /// for the first generation `repeat` whose [`Board::checksum`] matches an earlier
+    /// generation `first_seen`, or `None` if none does.
+    ///
+    /// Unlike [`Board::settle`], states are only compared by checksum, so a collision could
+    /// report a false recurrence, though that's vanishingly unlikely.
+    pub fn first_recurrence(&self, max_steps: usize) -> Option<(usize, usize)> {
+        let mut board = self.clone();
+        let mut seen = HashMap::from([(board.checksum(), 0)]);
+        for step in 1..=max_steps {
+            board.advance();
+            if let Some(&first_seen) = seen.get(&board.checksum()) {
+                return Some((first_seen, step));
+            }
+            seen.insert(board.checksum(), step);
+        }
+        None
+    }
+
+    /// Returns how many steps it takes the board to die out, or `None` if any cells are still
+    /// alive after `max_steps`. A dead board takes 0 steps.
+    pub fn steps_to_extinction(&self, max_steps: usize) -> Option<usize> {
+        let mut board = self.clone();
+        for step in 0..=max_steps {
+            if board.population() == 0 {
+                return Some(step);
+            }
+            board.advance();
+        }
+        None
+    }
+
+    /// Returns an upper bound on the population after `steps` steps: the number of cells within
+    /// `steps` cells, counting diagonal moves as one, of a cell alive now.
+    ///
+    /// The reach is clipped to the grid and doesn't wrap, so the bound only holds for boards with
+    /// dead edges.
+    pub fn max_possible_population(&self, steps: usize) -> usize {
+        let (rows, cols) = (self.grid.rows(), self.grid.cols());
+        // whether any of `line` is set within `steps` of each index, using prefix counts
+        let dilate = |line: &[bool]| {
+            let prefix = std::iter::once(0)
+                .chain(line.iter().scan(0, |count, &cell| {
+                    *count += usize::from(cell);
+                    Some(*count)
+                }))
+                .collect_vec();
+            (0..line.len())
+                .map(|i| prefix[i.saturating_add(steps + 1).min(line.len())] > prefix[i.saturating_sub(steps)])
+                .collect_vec()
+        };
+
+        let across = (0..rows)
+            .map(|row| dilate(&self.grid.iter_row(row).copied().collect_vec()))
+            .collect_vec();
+        (0..cols)
+            .map(|col| {
+                let column = across.iter().map(|row| row[col]).collect_vec();
+                dilate(&column).into_iter().filter(|&reached| reached).count()
+            })
+            .sum()
+    }
+
+    /// Returns the number of live cells on the board.
+    pub fn population(&self) -> usize {
+        self.grid.iter().filter(|&&cell| cell).count()
+    }
+
+    /// Iterates over the coordinates of every live cell in row-major order.
+    pub fn live_cells(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..self.grid.rows()).cartesian_product(0..self.grid.cols())
+            .filter(|&(row, col)| self.grid[row][col])
+    }
+
+    /// Returns the live cells, in row-major order, that have at least one dead neighbor.
+    ///
+    /// Neighbors past the edge count as dead whatever the board's edge modes, so every live cell
+    /// on the rim is part of the boundary.
+    pub fn boundary(&self) -> Vec<(usize, usize)> {
+        let (rows, cols) = (self.grid.rows(), self.grid.cols());
+        self.live_cells()
+            .filter(|&(row, col)| {
+                let on_rim = row == 0 || col == 0 || row == rows - 1 || col == cols - 1;
+                // away from the rim every neighbor is on the grid, whatever the edge modes
+                on_rim || self.counts[row][col] < 8
+            })
+            .collect()
+    }
+
+    /// Returns the share of live cells on the [`Board::boundary`], or `None` for a dead board.
+    /// Solid shapes score lower than thin or scattered ones, which score 1.
+    pub fn compactness(&self) -> Option<f64> {
+        match self.population() {
+            0 => None,
+            population => Some(self.boundary().len() as f64 / population as f64),
+        }
+    }
+
+    /// Splits the live cells into clusters connected through any of their 8 neighbors, each in
+    /// row-major order. Clusters are ordered by their first cell, and never connect across the
+    /// board's edges.
+    pub fn clusters(&self) -> Vec<Vec<(usize, usize)>> {
+        let (rows, cols) = (self.grid.rows(), self.grid.cols());
+        let mut seen = Grid::new(rows, cols);
+        let mut clusters = vec![];
+        for (row, col) in self.live_cells() {
+            if seen[row][col] {
+                continue;
+            }
+            seen[row][col] = true;
+            let (mut cluster, mut stack) = (vec![], vec![(row, col)]);
+            while let Some((r, c)) = stack.pop() {
+                cluster.push((r, c));
+                for &(dr, dc) in &NEIGHBOR_OFFSETS {
+                    let (Some(nr), Some(nc)) = (r.checked_add_signed(dr), c.checked_add_signed(dc)) else {
+                        continue;
+                    };
+                    if nr < rows && nc < cols && self.grid[nr][nc] && !seen[nr][nc] {
+                        seen[nr][nc] = true;
+                        stack.push((nr, nc));
+                    }
+                }
+            }
+            cluster.sort_unstable();
+            clusters.push(cluster);
+        }
+        clusters
+    }
+
+    /// Returns a board of the same size and edges with only `cells` alive.
+    fn isolate(&self, cells: &[(usize, usize)]) -> Board {
+        let mut board = self.with_grid(Grid::new(self.grid.rows(), self.grid.cols()));
+        for &(row, col) in cells {
+            board.set(row, col, true);
+        }
+        board
+    }
+
+    /// Settles the board as [`Board::settle`] does and splits what's left into its
+    /// [`Board::clusters`], each trimmed to its own bounding box. Returns `None` if the board
+    /// doesn't settle within `max_steps`.
+    ///
+    /// Oscillators are returned in the phase the settled board is in.
+    pub fn ash(&self, max_steps: usize) -> Option<Vec<Board>> {
+        let (settled, _) = self.settle(max_steps)?;
+        Some(settled.clusters().iter().map(|cluster| settled.isolate(cluster).trim()).collect())
+    }
+
+    /// Picks a live cell uniformly at random, or `None` if the board is dead.
+    pub fn random_live_cell<R: Rng>(&self, rng: &mut R) -> Option<(usize, usize)> {
+        self.live_cells().collect_vec().choose(rng).copied()
+    }
+
+    /// Returns the binary Shannon entropy, in bits, of the fraction of cells that are alive.
+    ///
+    /// This is 0.0 for an all-dead, all-alive or empty board and peaks at 1.0 when exactly half
+    /// the cells are alive.
+    pub fn entropy(&self) -> f64 {
+        let cells = self.grid.rows() * self.grid.cols();
+        if cells == 0 {
+            return 0.0;
+        }
+        let p = self.population() as f64 / cells as f64;
+        [p, 1.0 - p].into_iter()
+            .filter(|&q| q > 0.0)
+            .map(|q| -q * q.log2())
+            .sum()
+    }
+
+    /// Returns a stable 64-bit hash of the live cell coordinates, suitable for cheaply comparing
+    /// states across runs and implementations.
+    ///
+    /// This is FNV-1a over each live cell's `(row, col)` in row-major order, so it doesn't depend
+    /// on the standard library's hasher.
+    pub fn checksum(&self) -> u64 {
+        const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        let (rows, cols) = (self.grid.rows(), self.grid.cols());
+        (0..rows).cartesian_product(0..cols)
+            .filter(|&(row, col)| self.grid[row][col])
+            .flat_map(|(row, col)| (row as u64).to_le_bytes().into_iter().chain((col as u64).to_le_bytes()))
+            .fold(OFFSET_BASIS, |hash, byte| (hash ^ byte as u64).wrapping_mul(PRIME))
+    }
+
+    /// Advances a clone of the board `steps` times, returning the checksum after each step.
+    pub fn checksum_trace(&self, steps: usize) -> Vec<u64> {
+        let mut board = self.clone();
+        (0..steps)
+            .map(|_| {
+                board.advance();
+                board.checksum()
+            })
+            .collect()
+    }
+
+    /// Advances the board until its [`Board::checksum`] equals `target`, returning how many
+    /// steps that took, or `None` if it doesn't within `max_steps`. A board already matching
+    /// takes 0 steps.
+    ///
+    /// The board is left at the matching generation, or `max_steps` on if none matched. Step
+    /// `n` here matches entry `n - 1` of [`Board::checksum_trace`].
+    pub fn advance_until_checksum(&mut self, target: u64, max_steps: usize) -> Option<usize> {
+        for step in 0..=max_steps {
+            if self.checksum() == target {
+                return Some(step);
+            }
+            if step < max_steps {
+                self.advance();
+            }
+        }
+        None
+    }
+
+    fn count_live_neighbors(&self, row: usize, col: usize) -> usize {
+        // literally just binding to reduce the times writing self.grid
+        let grid = &self.grid;
+
+        self.edges.count_live_neighbors(row, col, (grid.rows(), grid.cols()), |r, c| grid[r][c])
+    }
+
+    /// Counts the live cells in the 3x3 block centered on `(row, col)`, including the cell itself.
+    ///
+    /// Edges are handled the same way as in `count_live_neighbors`.
+    pub fn count_block(&self, row: usize, col: usize) -> usize {
+        usize::from(self.counts[row][col]) + usize::from(self.grid[row][col])
+    }
+
+    /// Renders the board with Unicode Braille characters, packing each 2-wide by 4-tall block
+    /// of cells into a single glyph.
+    ///
+    /// The board is padded with dead cells up to multiples of 2 columns and 4 rows, so the output
+    /// has `ceil(rows / 4)` lines of `ceil(cols / 2)` glyphs each.
+    pub fn to_braille(&self) -> String {
+        // bit offsets from U+2800 for each (row, col) within a 4x2 block
+        const DOTS: [[u32; 2]; 4] = [
+            [0x01, 0x08],
+            [0x02, 0x10],
+            [0x04, 0x20],
+            [0x40, 0x80],
+        ];
+
+        let (rows, cols) = (self.grid.rows(), self.grid.cols());
+        let mut out = String::new();
+        for block_row in (0..rows).step_by(4) {
+            for block_col in (0..cols).step_by(2) {
+                let bits = (0..4).cartesian_product(0..2)
+                    .filter(|&(r, c)| block_row + r < rows && block_col + c < cols)
+                    .filter(|&(r, c)| self.grid[block_row + r][block_col + c])
+                    .fold(0, |acc, (r, c)| acc | DOTS[r][c]);
+                // every value in 0x2800..=0x28FF is a valid char
+                out.push(char::from_u32(0x2800 + bits).unwrap());
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Renders the board as an SVG document with one black `cell_size` square per live cell on a
+    /// white background rectangle. Dead cells emit nothing.
+    pub fn to_svg(&self, cell_size: u32) -> String {
+        let (rows, cols) = (self.grid.rows(), self.grid.cols());
+        let (width, height) = (cols as u64 * cell_size as u64, rows as u64 * cell_size as u64);
+
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" viewBox=\"0 0 {width} {height}\">\n"
+        );
+        svg.push_str("<rect width=\"100%\" height=\"100%\" fill=\"white\"/>\n");
+        for (row, col) in (0..rows).cartesian_product(0..cols).filter(|&(r, c)| self.grid[r][c]) {
+            let (x, y) = (col as u64 * cell_size as u64, row as u64 * cell_size as u64);
+            svg.push_str(&format!(
+                "<rect x=\"{x}\" y=\"{y}\" width=\"{cell_size}\" height=\"{cell_size}\" fill=\"black\"/>\n"
+            ));
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// Thins the live cells down to a skeleton one cell wide with the Zhang-Suen algorithm,
+    /// peeling cells off the region's outline until nothing more can be removed without breaking
+    /// it apart. Cells past the board's edges count as dead.
+    pub fn skeletonize(&self) -> Board {
+        let (rows, cols) = (self.grid.rows(), self.grid.cols());
+        let mut grid = self.grid.clone();
+        // clockwise from the north: P2 through P9 in Zhang and Suen's naming
+        const RING: [(isize, isize); 8] = [(-1, 0), (-1, 1), (0, 1), (1, 1), (1, 0), (1, -1), (0, -1), (-1, -1)];
+        let ring = |grid: &Grid<bool>, row: usize, col: usize| {
+            RING.map(|(dr, dc)| match (row.checked_add_signed(dr), col.checked_add_signed(dc)) {
+                (Some(r), Some(c)) if r < rows && c < cols => grid[r][c],
+                _ => false,
+            })
+        };
+
+        loop {
+            let mut changed = false;
+            for pass in 0..2 {
+                let removable = (0..rows).cartesian_product(0..cols)
+                    .filter(|&(row, col)| grid[row][col])
+                    .filter(|&(row, col)| {
+                        let p = ring(&grid, row, col);
+                        let live = p.iter().filter(|&&cell| cell).count();
+                        let transitions = (0..8).filter(|&i| !p[i] && p[(i + 1) % 8]).count();
+                        let (north, east, south, west) = (p[0], p[2], p[4], p[6]);
+                        let sides = if pass == 0 {
+                            !(east && south && (north || west))
+                        } else {
+                            !(north && west && (east || south))
+                        };
+                        (2..=6).contains(&live) && transitions == 1 && sides
+                    })
+                    .collect_vec();
+                changed |= !removable.is_empty();
+                for (row, col) in removable {
+                    grid[row][col] = false;
+                }
+            }
+            if !changed {
+                return self.with_grid(grid);
+            }
+        }
+    }
+
+    /// Finds the `height` x `width` window holding the most live cells, returning its top-left
+    /// corner and live count as `(row, col, count)`. Ties go to the first window in row-major
+    /// order.
+    ///
+    /// Returns `None` if the window is empty or larger than the board.
+    pub fn densest_window(&self, height: usize, width: usize) -> Option<(usize, usize, usize)> {
+        let (rows, cols) = (self.grid.rows(), self.grid.cols());
+        if height == 0 || width == 0 || height > rows || width > cols {
+            return None;
+        }
+        // sums[r][c] counts the live cells above and left of (r, c)
+        let mut sums = vec![vec![0; cols + 1]; rows + 1];
+        for (row, col) in (0..rows).cartesian_product(0..cols) {
+            sums[row + 1][col + 1] = usize::from(self.grid[row][col]) + sums[row][col + 1] + sums[row + 1][col]
+                - sums[row][col];
+        }
+        (0..=rows - height).cartesian_product(0..=cols - width)
+            .map(|(row, col)| {
+                let (bottom, right) = (row + height, col + width);
+                (row, col, sums[bottom][right] + sums[row][col] - sums[row][right] - sums[bottom][col])
+            })
+            .max_by_key(|&(row, col, count)| (count, std::cmp::Reverse((row, col))))
+    }
+
+    /// Returns the vertices of the convex hull of the live cells' `(row, col)` coordinates,
+    /// found with Andrew's monotone chain.
+    ///
+    /// Vertices start from the first live cell in row-major order and run counterclockwise when
+    /// rows are drawn downward and columns rightward. Cells on the hull's edges aren't vertices,
+    /// so a straight line of cells gives just its two ends. Fewer than 3 live cells give an
+    /// empty hull.
+    pub fn convex_hull(&self) -> Vec<(usize, usize)> {
+        // already sorted by row then column
+        let cells = self.live_cells().collect_vec();
+        if cells.len() < 3 {
+            return vec![];
+        }
+        // positive when o -> a -> b turns counterclockwise
+        let cross = |o: (usize, usize), a: (usize, usize), b: (usize, usize)| {
+            let (o, a, b) = ((o.0 as i64, o.1 as i64), (a.0 as i64, a.1 as i64), (b.0 as i64, b.1 as i64));
+            (a.0 - o.0) * (b.1 - o.1) - (a.1 - o.1) * (b.0 - o.0)
+        };
+        let half = |points: &mut dyn Iterator<Item = (usize, usize)>| {
+            let mut chain: Vec<(usize, usize)> = vec![];
+            for point in points {
+                while chain.len() >= 2 && cross(chain[chain.len() - 2], chain[chain.len() - 1], point) <= 0 {
+                    chain.pop();
+                }
+                chain.push(point);
+            }
+            chain.pop();
+            chain
+        };
+
+        let mut hull = half(&mut cells.iter().copied());
+        hull.extend(half(&mut cells.iter().rev().copied()));
+        hull
+    }
+
+    /// Returns the coordinates `(top, left, height, width)` of the smallest rectangle containing
+    /// every live cell, or `None` if the board is dead.
+    pub fn bounding_box(&self) -> Option<(usize, usize, usize, usize)> {
+        let (rows, cols) = (self.grid.rows(), self.grid.cols());
+        let live = (0..rows).cartesian_product(0..cols)
+            .filter(|&(row, col)| self.grid[row][col])
+            .collect_vec();
+
+        let (top, bottom) = live.iter().map(|&(row, _)| row).minmax().into_option()?;
+        let (left, right) = live.iter().map(|&(_, col)| col).minmax().into_option()?;
+        Some((top, left, bottom - top + 1, right - left + 1))
+    }
+
+    /// Returns the height and width of the [`Board::bounding_box`] and its aspect ratio, width
+    /// over height, or `None` if the board is dead. Wide patterns have ratios above 1.
+    pub fn shape_descriptor(&self) -> Option<(usize, usize, f64)> {
+        let (_, _, height, width) = self.bounding_box()?;
+        Some((height, width, width as f64 / height as f64))
+    }
+
+    /// Like [`Board::bounding_box`], but treats both axes as wrapping, so a pattern split across
+    /// an edge gets the smallest box that crosses the seam.
+    ///
+    /// The box is `(top, left, height, width)` as before, but `top + height` or `left + width`
+    /// may run past the board, in which case the box continues from the opposite edge.
+    pub fn toroidal_bounding_box(&self) -> Option<(usize, usize, usize, usize)> {
+        let (rows, cols) = (self.grid.rows(), self.grid.cols());
+        let occupied_rows = (0..rows).map(|row| self.grid.iter_row(row).any(|&cell| cell)).collect_vec();
+        let occupied_cols = (0..cols).map(|col| self.grid.iter_col(col).any(|&cell| cell)).collect_vec();
+
+        let (top, height) = circular_extent(&occupied_rows)?;
+        let (left, width) = circular_extent(&occupied_cols)?;
+        Some((top, left, height, width))
+    }
+
+    /// Returns a copy of the board surrounded by the given number of dead rows and columns on
+    /// each side.
+    pub fn pad(&self, top: usize, left: usize, bottom: usize, right: usize) -> Board {
+        let (rows, cols) = (self.grid.rows() + top + bottom, self.grid.cols() + left + right);
+        let mut padded = self.with_grid(Grid::init(rows, cols, false));
+        padded.stamp(self, top, left);
+        padded
+    }
+
+    /// Copies the live cells of `pattern` onto the board with the pattern's top-left corner at
+    /// `(row, col)`. Cells already alive stay alive, and parts of the pattern falling outside
+    /// the board are clipped.
+    pub fn stamp(&mut self, pattern: &Board, row: usize, col: usize) {
+        for (r, c) in pattern.live_cells() {
+            let (r, c) = (row.saturating_add(r), col.saturating_add(c));
+            if r < self.grid.rows() && c < self.grid.cols() {
+                self.set(r, c, true);
+            }
+        }
+    }
+
+    /// Repeats the board `rows_repeat` times vertically and `cols_repeat` times horizontally,
+    /// keeping its edge settings.
+    pub fn tile(&self, rows_repeat: usize, cols_repeat: usize) -> Board {
+        let (rows, cols) = (self.grid.rows(), self.grid.cols());
+        let mut tiled = self.with_grid(Grid::new(rows * rows_repeat, cols * cols_repeat));
+        tiled.set_each(|row, col| self.grid[row % rows][col % cols]);
+        tiled
+    }
+
+    /// Returns whether all of `pattern`, dead cells included, fits on the board with its top-left
+    /// corner at `(row, col)`, so [`Board::stamp`] wouldn't clip anything.
+    pub fn can_stamp(&self, pattern: &Board, row: usize, col: usize) -> bool {
+        let fits = |start: usize, len: usize, limit: usize| start.checked_add(len).is_some_and(|end| end <= limit);
+        fits(row, pattern.grid.rows(), self.grid.rows()) && fits(col, pattern.grid.cols(), self.grid.cols())
+    }
+
+    /// Pads the board with dead cells so both dimensions are powers of two, keeping the content
+    /// centered. When the padding on an axis is odd the extra cell goes on the bottom or right.
+    /// An empty board stays empty.
+    pub fn pad_to_pow2(&self) -> Board {
+        let (rows, cols) = (self.grid.rows(), self.grid.cols());
+        if rows == 0 {
+            return self.clone();
+        }
+        let (extra_rows, extra_cols) = (rows.next_power_of_two() - rows, cols.next_power_of_two() - cols);
+        self.pad(extra_rows / 2, extra_cols / 2, extra_rows - extra_rows / 2, extra_cols - extra_cols / 2)
+    }
+
+    /// Trims the board to its bounding box, then pads the shorter side with dead cells so the
+    /// result is square with the pattern centered. As with [`Board::pad_to_pow2`], odd padding
+    /// puts the extra cell on the bottom or right.
+    pub fn to_square(&self) -> Board {
+        let trimmed = self.trim();
+        let (rows, cols) = (trimmed.grid.rows(), trimmed.grid.cols());
+        let side = rows.max(cols);
+        let (extra_rows, extra_cols) = (side - rows, side - cols);
+        trimmed.pad(extra_rows / 2, extra_cols / 2, extra_rows - extra_rows / 2, extra_cols - extra_cols / 2)
+    }
+
+    /// Returns a copy of the board cropped to its bounding box. A dead board trims to 0x0.
+    pub fn trim(&self) -> Board {
+        match self.bounding_box() {
+            Some((top, left, height, width)) => self.crop(top, left, height, width),
+            None => self.with_grid(Grid::new(0, 0)),
+        }
+    }
+
+    /// Returns the `height` x `width` region of the board with its top-left corner at
+    /// `(top, left)`, clipped to the board.
+    pub fn crop(&self, top: usize, left: usize, height: usize, width: usize) -> Board {
+        let bottom = top.saturating_add(height).min(self.grid.rows());
+        let right = left.saturating_add(width).min(self.grid.cols());
+        if top >= bottom || left >= right {
+            return self.with_grid(Grid::new(0, 0));
+        }
+        let cells = (top..bottom).cartesian_product(left..right)
+            .map(|(row, col)| self.grid[row][col])
+            .collect_vec();
+
+        self.with_cells(cells, right - left)
+    }
+
+    /// Renders the board centered in a `width` x `height` field, drawing cells as `alive` or
+    /// `dead` and filling the rest of the field with `dead`. As with [`Board::pad_to_pow2`],
+    /// odd padding puts the extra cell on the bottom or right.
+    ///
+    /// Fails if the board doesn't fit in the field.
+    pub fn to_string_padded(&self, width: usize, height: usize, dead: char, alive: char) -> Result<String, ShapeError> {
+        let (rows, cols) = (self.grid.rows(), self.grid.cols());
+        if rows > height || cols > width {
+            return Err(ShapeError::TooLarge { limit: (height, width), found: (rows, cols) });
+        }
+        let (extra_rows, extra_cols) = (height - rows, width - cols);
+        let padded = self.pad(extra_rows / 2, extra_cols / 2, extra_rows - extra_rows / 2, extra_cols - extra_cols / 2);
+
+        let mut out = String::new();
+        for row in 0..height {
+            out.extend((0..width).map(|col| if padded.get(row, col) == Some(true) { alive } else { dead }));
+            out.push('\n');
+        }
+        Ok(out)
+    }
+
+    /// Renders the board with cells drawn as `alive` or `dead`, except the `(row, col)` cell
+    /// under `cursor`, which is drawn as `cursor_char` whatever its state. A cursor off the
+    /// board draws nothing.
+    pub fn to_string_with_cursor(&self, cursor: (usize, usize), dead: char, alive: char, cursor_char: char) -> String {
+        let mut out = String::new();
+        for row in 0..self.grid.rows() {
+            out.extend((0..self.grid.cols()).map(|col| {
+                if (row, col) == cursor {
+                    cursor_char
+                } else if self.grid[row][col] {
+                    alive
+                } else {
+                    dead
+                }
+            }));
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Lays `boards` out in rows of `columns`, left to right, with `gap` dead cells between
+    /// neighbors. Each board is centered in a slot the size of the largest one, as in
+    /// [`Board::to_string_padded`], and a short last row is filled out with dead cells.
+    ///
+    /// A `columns` of 0 is treated as 1.
+    pub fn contact_sheet(boards: &[Board], columns: usize, gap: usize, dead: char, alive: char) -> String {
+        if boards.is_empty() {
+            return String::new();
+        }
+        let columns = columns.clamp(1, boards.len());
+        let height = boards.iter().map(Board::rows).max().unwrap_or(0);
+        let width = boards.iter().map(Board::cols).max().unwrap_or(0);
+        let sheet_width = columns * width + columns.saturating_sub(1) * gap;
+        let blank_line = dead.to_string().repeat(sheet_width);
+
+        let mut lines = vec![];
+        for (i, chunk) in boards.chunks(columns).enumerate() {
+            if i > 0 {
+                lines.extend(std::iter::repeat_n(blank_line.clone(), gap));
+            }
+            let slots = chunk.iter()
+                .map(|board| {
+                    board.to_string_padded(width, height, dead, alive)
+                        .expect("every board fits in the largest size")
+                })
+                .collect_vec();
+            for line in 0..height {
+                let mut text = slots.iter()
+                    .map(|slot| slot.lines().nth(line).unwrap_or_default())
+                    .join(&dead.to_string().repeat(gap));
+                // pad out to the full width, counting characters rather than bytes
+                text.extend(std::iter::repeat_n(dead, sheet_width - text.chars().count()));
+                lines.push(text);
+            }
+        }
+        lines.into_iter().map(|line| line + "\n").collect()
+    }
+
+    /// Summarizes each row as its runs of dead and alive cells, like `3d 2a 5d`, one row per
+    /// line.
+    pub fn to_run_summary(&self) -> String {
+        let mut summary = String::new();
+        for row in 0..self.grid.rows() {
+            let runs = self.grid.iter_row(row)
+                .dedup_with_count()
+                .map(|(len, &alive)| format!("{len}{}", if alive { 'a' } else { 'd' }))
+                .join(" ");
+            summary.push_str(&runs);
+            summary.push('\n');
+        }
+        summary
+    }
+
+    /// Renders only the board's bounding box, expanded by `margin` cells on each side and
+    /// clipped to the grid. A dead board renders as `(empty)`.
+    pub fn to_string_trimmed(&self, margin: usize) -> String {
+        let Some((top, left, height, width)) = self.bounding_box() else {
+            return "(empty)\n".to_string();
+        };
+        let (new_top, new_left) = (top.saturating_sub(margin), left.saturating_sub(margin));
+        self.crop(
+            new_top,
+            new_left,
+            height + margin + (top - new_top),
+            width + margin + (left - new_left),
+        ).to_string()
+    }
+
+    /// Returns a copy of the board with the given rotation or reflection applied.
+    pub fn transform(&self, transform: Transform) -> Board {
+        let (rows, cols) = (self.grid.rows(), self.grid.cols());
+        let (new_rows, new_cols) = if transform.swaps_axes() { (cols, rows) } else { (rows, cols) };
+
+        let cells = (0..new_rows).cartesian_product(0..new_cols)
+            .map(|(r, c)| {
+                let (src_row, src_col) = match transform {
+                    Transform::Identity => (r, c),
+                    Transform::Rotate90 => (rows - 1 - c, r),
+                    Transform::Rotate180 => (rows - 1 - r, cols - 1 - c),
+                    Transform::Rotate270 => (c, cols - 1 - r),
+                    Transform::FlipHorizontal => (r, cols - 1 - c),
+                    Transform::FlipVertical => (rows - 1 - r, c),
+                    Transform::FlipDiagonal => (c, r),
+                    Transform::FlipAntiDiagonal => (rows - 1 - c, cols - 1 - r),
+                };
+                self.grid[src_row][src_col]
+            })
+            .collect_vec();
+
+        self.with_cells(cells, new_cols)
+    }
+
+    /// Makes the board symmetric across `axis` by bringing to life every cell whose mirror image
+    /// is alive, so it afterwards equals its own reflection under the matching [`Transform`].
+    pub fn symmetrize(&mut self, axis: Axis) {
+        for &transform in axis.transforms() {
+            let mirror = self.transform(transform);
+            *self = self.combine(&mirror, BoolOp::Or).expect("reflections keep the board's size");
+        }
+    }
+
+    /// Combines two equally sized boards cell by cell with `op`.
+    pub fn combine(&self, other: &Board, op: BoolOp) -> Result<Board, ShapeError> {
+        self.check_same_size(other)?;
+        let cells = self.grid.iter().zip(other.grid.iter())
+            .map(|(&a, &b)| op.apply(a, b))
+            .collect_vec();
+
+        Ok(self.with_grid(build_grid(cells, self.grid.cols())?))
+    }
+
+    /// Returns the number of `(births, deaths)` the next step will cause.
+    pub fn transition_counts(&self) -> (usize, usize) {
+        let next = self.next_generation();
+        self.grid.iter().zip(next.grid.iter())
+            .fold((0, 0), |(births, deaths), (&now, &then)| match (now, then) {
+                (false, true) => (births + 1, deaths),
+                (true, false) => (births, deaths + 1),
+                _ => (births, deaths),
+            })
+    }
+
+    /// Counts the cells that differ between two equally sized boards.
+    pub fn hamming_distance(&self, target: &Board) -> Result<usize, ShapeError> {
+        self.check_same_size(target)?;
+        Ok(self.grid.iter().zip(target.grid.iter()).filter(|(a, b)| a != b).count())
+    }
+
+    /// Advances clones of both boards in lockstep and returns whether their cells agree at every
+    /// generation from 0 through `steps`, stopping at the first that doesn't.
+    ///
+    /// Only cells are compared, so boards with different edge modes match until the edges make
+    /// a difference.
+    pub fn traces_match(&self, other: &Board, steps: usize) -> bool {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        for step in 0..=steps {
+            if a.grid != b.grid {
+                return false;
+            }
+            if step < steps {
+                a.advance();
+                b.advance();
+            }
+        }
+        true
+    }
+
+    /// Advances clones of both boards in lockstep, returning the [`Board::hamming_distance`]
+    /// between them at generation 0 and after each of the next `steps` steps.
+    pub fn divergence(&self, other: &Board, steps: usize) -> Result<Vec<usize>, ShapeError> {
+        self.check_same_size(other)?;
+        let (mut a, mut b) = (self.clone(), other.clone());
+        let mut distances = vec![a.hamming_distance(&b)?];
+        for _ in 0..steps {
+            a.advance();
+            b.advance();
+            distances.push(a.hamming_distance(&b)?);
+        }
+        Ok(distances)
+    }
+
+    /// Returns a conservative mask of cells that can't be alive next step under the board's
+    /// rule: dead cells with fewer live neighbors than the smallest birth count, and live cells
+    /// whose count isn't a survival count.
+    ///
+    /// Dead cells with enough neighbors aren't marked even if their exact count can't cause a
+    /// birth, so every marked cell is dead next step but not every cell that will be is marked.
+    pub fn definitely_dead_next(&self) -> Board {
+        let min_birth = self.rule.births().min().unwrap_or(usize::MAX);
+        let mut mask = self.with_grid(Grid::new(self.grid.rows(), self.grid.cols()));
+        mask.set_each(|row, col| {
+            let neighbors = usize::from(self.counts[row][col]);
+            if self.grid[row][col] {
+                !self.rule.next_state(true, neighbors)
+            } else {
+                neighbors < min_birth
+            }
+        });
+        mask
+    }
+
+    /// Returns a board marking the cells that differ between the board and its generation
+    /// `steps` steps later. [`Board::change_mask`] is the one-step case.
+    pub fn diff_future(&self, steps: usize) -> Board {
+        self.combine(&self.phase(steps), BoolOp::Xor)
+            .expect("later generations are always the same size")
+    }
+
+    /// Returns a board marking the cells that will flip on the next step.
+    pub fn change_mask(&self) -> Board {
+        self.combine(&self.next_generation(), BoolOp::Xor)
+            .expect("the next generation is always the same size")
+    }
+
+    fn check_same_size(&self, other: &Board) -> Result<(), ShapeError> {
+        if self.grid.size() != other.grid.size() {
+            return Err(ShapeError::Mismatch { expected: self.grid.size(), found: other.grid.size() });
+        }
+        Ok(())
+    }
+
+    /// Returns whether the two boards hold the same pattern up to translation, rotation and
+    /// reflection. Board sizes and edge settings are ignored.
+    pub fn is_equivalent(&self, other: &Board) -> bool {
+        let (mine, theirs) = (self.trim(), other.trim());
+        Transform::ALL.into_iter().any(|t| mine.transform(t).grid == theirs.grid)
+    }
+
+    /// Stamps a glider heading in `orientation` onto the board, with the top-left corner of its
+    /// 3x3 bounding box at `(row, col)`, clipped as in [`Board::stamp`]. The glider is always in
+    /// the same phase, with its three-cell edge leading.
+    pub fn inject_glider(&mut self, row: usize, col: usize, orientation: Orientation) {
+        let transform = match orientation {
+            Orientation::SouthEast => Transform::Identity,
+            Orientation::SouthWest => Transform::FlipHorizontal,
+            Orientation::NorthEast => Transform::FlipVertical,
+            Orientation::NorthWest => Transform::Rotate180,
+        };
+        let glider: Board = ".O.\n..O\nOOO".parse().expect("the glider is well formed");
+        self.stamp(&glider.transform(transform), row, col);
+    }
+
+    /// Finds isolated gliders, returning the top-left corner of each one's 3x3 bounding box and
+    /// its direction of travel.
+    ///
+    /// Any phase of a glider in any orientation is recognized, but only when every cell
+    /// bordering its bounding box is dead, so gliders touching other cells are not reported.
+    pub fn find_gliders(&self) -> Vec<(usize, usize, Orientation)> {
+        let (rows, cols) = (self.grid.rows(), self.grid.cols());
+        if rows < 3 || cols < 3 {
+            return vec![];
+        }
+        let templates = glider_templates();
+        let is_alive = |row: isize, col: isize| {
+            row >= 0 && col >= 0 && (row as usize) < rows && (col as usize) < cols
+                && self.grid[row as usize][col as usize]
+        };
+
+        (0..=rows - 3).cartesian_product(0..=cols - 3)
+            .filter_map(|(top, left)| {
+                let (template, orientation) = templates.iter().find(|(template, _)| {
+                    (0..3).cartesian_product(0..3)
+                        .all(|(r, c)| template.grid[r][c] == self.grid[top + r][left + c])
+                })?;
+                // the 5x5 ring around the window must be empty for the glider to be isolated
+                let (top, left) = (top as isize, left as isize);
+                let isolated = (top - 1..=top + 3).cartesian_product(left - 1..=left + 3)
+                    .filter(|&(r, c)| !(top..top + 3).contains(&r) || !(left..left + 3).contains(&c))
+                    .all(|(r, c)| !is_alive(r, c));
+                (isolated && template.population() > 0).then_some((top as usize, left as usize, *orientation))
+            })
+            .collect()
+    }
+
+    /// Returns a board marking every cell that `(row, col)` could influence within `steps`
+    /// generations: its light cone, the cells within Chebyshev distance `steps`, clipped to
+    /// the grid.
+    pub fn influence_region(&self, row: usize, col: usize, steps: usize) -> Board {
+        let mut region = self.with_grid(Grid::init(self.grid.rows(), self.grid.cols(), false));
+        region.set_each(|r, c| r.abs_diff(row) <= steps && c.abs_diff(col) <= steps);
+        region
+    }
+
+    /// Reports which of the 8 dihedral transforms leave the trimmed pattern unchanged.
+    ///
+    /// The identity is always included, even for a dead board.
+    pub fn symmetries(&self) -> SymmetrySet {
+        let trimmed = self.trim();
+        Transform::ALL.into_iter()
+            .filter(|&t| trimmed.transform(t) == trimmed)
+            .fold(SymmetrySet::empty(), |set, t| set.with(t))
+    }
+}
+
+/// An error from parsing a board out of one of the supported pattern formats.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// The input contained no pattern data.
+    Empty,
+    /// A line of a text format was malformed. Lines are numbered from 1.
+    Syntax { line: usize, reason: String },
+    /// A binary buffer didn't start with the expected magic bytes.
+    BadMagic,
+    /// A binary buffer was written by an unknown format version.
+    UnsupportedVersion(u8),
+    /// A binary buffer was the wrong length for its header.
+    Length { expected: usize, found: usize },
+    /// A base64 string couldn't be decoded.
+    Base64(String),
+    /// Reading the input failed.
+    Io(String),
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "no pattern data found"),
+            ParseError::Syntax { line, reason } => write!(f, "line {line}: {reason}"),
+            ParseError::BadMagic => write!(f, "not a board buffer"),
+            ParseError::UnsupportedVersion(v) => write!(f, "unsupported format version {v}"),
+            ParseError::Length { expected, found } => write!(f, "expected {expected} bytes, found {found}"),
+            ParseError::Base64(reason) => write!(f, "invalid base64: {reason}"),
+            ParseError::Io(reason) => write!(f, "couldn't read input: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A rectangle of cells, with its top-left corner at `(row, col)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect {
+    pub row: usize,
+    pub col: usize,
+    pub height: usize,
+    pub width: usize,
+}
+
+impl Rect {
+    pub fn new(row: usize, col: usize, height: usize, width: usize) -> Self {
+        Rect { row, col, height, width }
+    }
+
+    pub fn contains(&self, row: usize, col: usize) -> bool {
+        (self.row..self.row + self.height).contains(&row) && (self.col..self.col + self.width).contains(&col)
+    }
+}
+
+/// The reflections [`Board::symmetrize`] can enforce. `Horizontal` mirrors left-to-right, as
+/// [`Transform::FlipHorizontal`] does, and `Vertical` mirrors top-to-bottom.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Axis {
+    Horizontal,
+    Vertical,
+    Both,
+}
+
+impl Axis {
+    fn transforms(self) -> &'static [Transform] {
+        match self {
+            Axis::Horizontal => &[Transform::FlipHorizontal],
+            Axis::Vertical => &[Transform::FlipVertical],
+            Axis::Both => &[Transform::FlipHorizontal, Transform::FlipVertical],
+        }
+    }
+}
+
+/// A cell-wise boolean operation for [`Board::combine`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BoolOp {
+    And,
+    Or,
+    Xor,
+    /// Cells alive in the first board but not the second.
+    AndNot,
+}
+
+impl BoolOp {
+    pub fn apply(self, a: bool, b: bool) -> bool {
+        match self {
+            BoolOp::And => a && b,
+            BoolOp::Or => a || b,
+            BoolOp::Xor => a ^ b,
+            BoolOp::AndNot => a && !b,
+        }
+    }
+}
+
+/// An error from an operation whose inputs had incompatible dimensions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShapeError {
+    /// Two boards were expected to be the same size. Sizes are `(rows, cols)`.
+    Mismatch { expected: (usize, usize), found: (usize, usize) },
+    /// More cells were requested than a board of the given size can hold.
+    Capacity { capacity: usize, requested: usize },
+    /// A list of `cells` couldn't be split evenly into rows of `cols` cells.
+    Ragged { cells: usize, cols: usize },
+    /// Row `row` held `found` cells where the rows before it held `expected`.
+    RowLength { row: usize, expected: usize, found: usize },
+    /// A buffer held `found` cells where the board has `expected`.
+    Length { expected: usize, found: usize },
+    /// A board didn't fit in the space it was given. Sizes are `(rows, cols)`.
+    TooLarge { limit: (usize, usize), found: (usize, usize) },
+    /// The weight for the cell at `(row, col)` wasn't a probability between 0 and 1.
+    Weight { row: usize, col: usize },
+    /// A board of `cells` cells was too large to search exhaustively, the limit being `limit`.
+    SearchSize { limit: usize, cells: usize },
+    /// A live cell at `(row, col)` fell outside the board it was being placed on.
+    OutOfBounds { row: i64, col: i64 },
+}
+
+impl Display for ShapeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShapeError::Mismatch { expected, found } => write!(
+                f,
+                "expected a {}x{} board, found {}x{}",
+                expected.0, expected.1, found.0, found.1
+            ),
+            ShapeError::Capacity { capacity, requested } => {
+                write!(f, "requested {requested} cells, but only {capacity} fit")
+            }
+            ShapeError::Ragged { cells, cols } => {
+                write!(f, "{cells} cells don't split into rows of {cols}")
+            }
+            ShapeError::RowLength { row, expected, found } => {
+                write!(f, "row {row} has {found} cells, but the rows before it have {expected}")
+            }
+            ShapeError::Length { expected, found } => {
+                write!(f, "expected a buffer of {expected} cells, found {found}")
+            }
+            ShapeError::TooLarge { limit, found } => write!(
+                f,
+                "a {}x{} board doesn't fit in {}x{}",
+                found.0, found.1, limit.0, limit.1
+            ),
+            ShapeError::Weight { row, col } => {
+                write!(f, "the weight at ({row}, {col}) isn't between 0 and 1")
+            }
+            ShapeError::SearchSize { limit, cells } => {
+                write!(f, "a board of {cells} cells is too large to search, the limit is {limit}")
+            }
+            ShapeError::OutOfBounds { row, col } => {
+                write!(f, "the cell at ({row}, {col}) is off the board")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ShapeError {}
+
+/// Lays `cells` out row-major in rows of `cols`, checking the length up front rather than letting
+/// `Grid::from_vec` panic. No cells make an empty grid whatever `cols` is.
+fn build_grid<T>(cells: Vec<T>, cols: usize) -> Result<Grid<T>, ShapeError> {
+    if cells.is_empty() {
+        return Ok(Grid::from_vec(cells, 0));
+    }
+    if !cells.len().is_multiple_of(cols) {
+        return Err(ShapeError::Ragged { cells: cells.len(), cols });
+    }
+    Ok(Grid::from_vec(cells, cols))
+}
+
+/// Finds the shortest circular span `(start, len)` covering every occupied index, by cutting the
+/// circle at its longest empty run. Ties keep the span that doesn't wrap.
+fn circular_extent(occupied: &[bool]) -> Option<(usize, usize)> {
+    let n = occupied.len();
+    let first = occupied.iter().position(|&o| o)?;
+    let last = occupied.iter().rposition(|&o| o)?;
+
+    // the run across the seam, then any longer one in the middle
+    let (mut gap_start, mut gap_len) = ((last + 1) % n, n - 1 - last + first);
+    let mut i = first;
+    while i < last {
+        let run = occupied[i + 1..].iter().take_while(|&&o| !o).count();
+        if run > gap_len {
+            (gap_start, gap_len) = (i + 1, run);
+        }
+        i += run + 1;
+    }
+    Some(((gap_start + gap_len) % n, n - gap_len))
+}
+
+/// How a board's evolution ends, as found by [`Board::classify`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lifespan {
+    /// Every cell is dead from this generation on.
+    Extinct(usize),
+    /// The board enters a cycle of `period` states at generation `step`. Still lifes have a
+    /// period of 1.
+    Stabilized { step: usize, period: usize },
+    /// No state repeated within the step limit.
+    Active,
+}
+
+/// A diagonal direction of travel, as for a glider.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    NorthEast,
+    NorthWest,
+    SouthEast,
+    SouthWest,
+}
+
+impl Orientation {
+    /// The `(row, col)` unit step in this direction, where rows increase southward.
+    pub fn delta(self) -> (isize, isize) {
+        match self {
+            Orientation::NorthEast => (-1, 1),
+            Orientation::NorthWest => (-1, -1),
+            Orientation::SouthEast => (1, 1),
+            Orientation::SouthWest => (1, -1),
+        }
+    }
+
+    fn from_delta(delta: (isize, isize)) -> Self {
+        match delta {
+            (-1, 1) => Orientation::NorthEast,
+            (-1, -1) => Orientation::NorthWest,
+            (1, 1) => Orientation::SouthEast,
+            _ => Orientation::SouthWest,
+        }
+    }
+
+    /// The direction a pattern heading this way travels after `transform` is applied to it.
+    pub fn transformed(self, transform: Transform) -> Self {
+        let (dr, dc) = self.delta();
+        Orientation::from_delta(match transform {
+            Transform::Identity => (dr, dc),
+            Transform::Rotate90 => (dc, -dr),
+            Transform::Rotate180 => (-dr, -dc),
+            Transform::Rotate270 => (-dc, dr),
+            Transform::FlipHorizontal => (dr, -dc),
+            Transform::FlipVertical => (-dr, dc),
+            Transform::FlipDiagonal => (dc, dr),
+            Transform::FlipAntiDiagonal => (-dc, -dr),
+        })
+    }
+}
+
+/// Every phase of the glider in every orientation, as 3x3 boards paired with their heading.
+fn glider_templates() -> Vec<(Board, Orientation)> {
+    // the first phase of a south-east glider, padded so it can advance without hitting the edge
+    let mut glider = Board::dead(7, 7);
+    glider.set_each(|row, col| matches!((row, col), (2, 3) | (3, 4) | (4, 2..=4)));
+
+    let mut templates: Vec<(Board, Orientation)> = vec![];
+    for _ in 0..4 {
+        let phase = glider.trim();
+        for transform in Transform::ALL {
+            let template = (phase.transform(transform), Orientation::SouthEast.transformed(transform));
+            if !templates.contains(&template) {
+                templates.push(template);
+            }
+        }
+        glider.advance();
+    }
+    templates
+}
+
+/// One of the 8 rotations and reflections of a rectangular grid.
+///
+/// Rotations are clockwise. `FlipHorizontal` mirrors left-to-right, `FlipVertical` mirrors
+/// top-to-bottom, and the diagonal flips reflect across the main (top-left to bottom-right)
+/// and anti (top-right to bottom-left) diagonals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Transform {
+    Identity,
+    Rotate90,
+    Rotate180,
+    Rotate270,
+    FlipHorizontal,
+    FlipVertical,
+    FlipDiagonal,
+    FlipAntiDiagonal,
+}
+
+impl Transform {
+    pub const ALL: [Transform; 8] = [
+        Transform::Identity,
+        Transform::Rotate90,
+        Transform::Rotate180,
+        Transform::Rotate270,
+        Transform::FlipHorizontal,
+        Transform::FlipVertical,
+        Transform::FlipDiagonal,
+        Transform::FlipAntiDiagonal,
+    ];
+
+    /// Whether the transform exchanges the row and column dimensions.
+    pub fn swaps_axes(self) -> bool {
+        matches!(
+            self,
+            Transform::Rotate90 | Transform::Rotate270 | Transform::FlipDiagonal | Transform::FlipAntiDiagonal
+        )
+    }
+
+    fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+/// A set of [`Transform`]s, stored as one bit per transform.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SymmetrySet(u8);
+
+impl SymmetrySet {
+    pub const ALL: SymmetrySet = SymmetrySet(u8::MAX);
+
+    pub fn empty() -> Self {
+        SymmetrySet(0)
+    }
+
+    /// Returns a copy of the set with `transform` added.
+    pub fn with(self, transform: Transform) -> Self {
+        SymmetrySet(self.0 | transform.bit())
+    }
+
+    pub fn contains(self, transform: Transform) -> bool {
+        self.0 & transform.bit() != 0
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn bits(self) -> u8 {
+        self.0
+    }
+
+    pub fn iter(self) -> impl Iterator<Item = Transform> {
+        Transform::ALL.into_iter().filter(move |&t| self.contains(t))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_board_x_by_x<T: as_bool::AsBool>(vec: Vec<T>, x: usize) -> Board {
+        let vec = vec.iter().map(|i| i.as_bool()).collect();
+        Board::from_grid(build_grid(vec, x).unwrap())
+    }
+
+    fn block() -> Board {
+        create_board_x_by_x(vec![
+            0, 0, 0, 0,
+            0, 1, 1, 0,
+            0, 1, 1, 0,
+            0, 0, 0, 0,
+        ], 4)
+    }
+
+    fn blinker() -> Board {
+        create_board_x_by_x(vec![
+            0, 0, 0, 0, 0,
+            0, 0, 1, 0, 0,
+            0, 0, 1, 0, 0,
+            0, 0, 1, 0, 0,
+            0, 0, 0, 0, 0,
+        ], 5)
+    }
+
+    fn toad() -> Board {
+        create_board_x_by_x(vec![
+            0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+            0, 0, 1, 1, 1, 0,
+            0, 1, 1, 1, 0, 0,
+            0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ], 6)
+    }
+
+    #[test]
+    fn dead_0_neighbors_stay_dead() {
+        let mut board = create_board_x_by_x(vec![0;9], 3);
+        let expected = board.clone();
+        board.advance();
+        assert_eq!(expected, board);
+    }
+
+    #[test]
+    fn dead_3_neighbors_come_alive() {
+        let mut board = create_board_x_by_x(vec![
+            0, 0, 1,
+            0, 1, 1,
+            0, 0, 0,
+        ], 3);
+
+        let expected = create_board_x_by_x(vec![
+            0, 1, 1,
+            0, 1, 1,
+            0, 0, 0,
+        ], 3);
+
+        board.advance();
+        assert_eq!(expected, board);
+    }
+
+    #[test]
+    fn alive_4_neighbors_die() {
+        let mut board = create_board_x_by_x(vec![
+            0, 1, 1,
+            0, 1, 1,
+            0, 0, 1,
+        ], 3);
+
+        let expected = create_board_x_by_x(vec![
+            0, 1, 1,
+            0, 0, 0,
+            0, 1, 1,
+        ], 3);
+
+        board.advance();
+        assert_eq!(expected, board);
+    }
+
+    #[test]
+    fn common_still_lifes() {
+        let still_lifes = vec![
+            // block
+            create_board_x_by_x(vec![
+                0, 0, 0, 0,
+                0, 1, 1, 0,
+                0, 1, 1, 0,
+                0, 0, 0, 0,
+            ], 4),
+            
+            // beehive
+            create_board_x_by_x(vec![
+                0, 0, 0, 0, 0, 0,
+                0, 0, 1, 1, 0, 0,
+                0, 1, 0, 0, 1, 0,
+                0, 0, 1, 1, 0, 0,
+                0, 0, 0, 0, 0, 0,
+                0, 0, 0, 0, 0, 0,
+            ], 6),
+
+            // boat
+            create_board_x_by_x(vec![
+                0, 0, 0, 0, 0, 
+                0, 0, 1, 0, 0, 
+                0, 1, 0, 1, 0, 
+                0, 1, 1, 0, 0, 
+                0, 0, 0, 0, 0, 
+            ], 5),
+
+        ];
+
+        for mut board in still_lifes {
+            let expected = board.clone();
+    
+            board.advance();
+            assert_eq!(expected, board);
+        }
+    }
+
+    #[test]
+    fn simple_oscillators() {
+        let mut blinker = create_board_x_by_x(vec![
+            0, 0, 0, 0, 0, 
+            0, 0, 1, 0, 0, 
+            0, 0, 1, 0, 0, 
+            0, 0, 1, 0, 0, 
+            0, 0, 0, 0, 0, 
+        ], 5);
+
+        let expected = create_board_x_by_x(vec![
+            0, 0, 0, 0, 0, 
+            0, 0, 0, 0, 0, 
+            0, 1, 1, 1, 0, 
+            0, 0, 0, 0, 0, 
+            0, 0, 0, 0, 0, 
+        ], 5);
+
+        blinker.advance();
+        assert_eq!(expected, blinker);
+
+        let mut toad = create_board_x_by_x(vec![
+            0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+            0, 0, 1, 1, 1, 0,
+            0, 1, 1, 1, 0, 0,
+            0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ], 6);
+        
+        let expected = create_board_x_by_x(vec![
+            0, 0, 0, 0, 0, 0,
+            0, 0, 0, 1, 0, 0,
+            0, 1, 0, 0, 1, 0,
+            0, 1, 0, 0, 1, 0,
+            0, 0, 1, 0, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ], 6);
+
+        toad.advance();
+        assert_eq!(expected, toad);
+    }
+
+    #[test]
+    fn braille_packs_2x4_blocks() {
+        let full = create_board_x_by_x(vec![1; 8], 2);
+        assert_eq!("\u{28FF}\n", full.to_braille());
+
+        // left column of the first block, plus a lone cell in the padded second block
+        let board = create_board_x_by_x(vec![
+            1, 0, 0,
+            1, 0, 0,
+            1, 0, 0,
+            1, 0, 0,
+            0, 0, 1,
+        ], 3);
+        let braille = board.to_braille();
+        assert_eq!(2, braille.lines().count());
+        assert_eq!("\u{2847}\u{2800}\n\u{2800}\u{2801}\n", braille);
+    }
+
+    #[test]
+    fn count_block_includes_center() {
+        let board = create_board_x_by_x(vec![
+            1, 0, 1, 0,
+            0, 1, 1, 0,
+            0, 0, 0, 1,
+            1, 1, 0, 1,
+        ], 4);
+
+        for (row, col) in [(0, 0), (1, 1), (1, 2), (2, 2), (3, 3), (3, 0), (0, 3)] {
+            let expected = board.count_live_neighbors(row, col) + usize::from(board.grid[row][col]);
+            assert_eq!(expected, board.count_block(row, col));
+        }
+        assert_eq!(4, board.count_block(1, 1));
+    }
+
+    #[test]
+    fn block_is_fully_symmetric() {
+        let block = create_board_x_by_x(vec![
+            0, 0, 0, 0,
+            0, 1, 1, 0,
+            0, 1, 1, 0,
+            0, 0, 0, 0,
+        ], 4);
+        assert_eq!(SymmetrySet::ALL, block.symmetries());
+    }
+
+    #[test]
+    fn blinker_symmetries() {
+        let blinker = create_board_x_by_x(vec![
+            0, 0, 0, 0, 0,
+            0, 0, 1, 0, 0,
+            0, 0, 1, 0, 0,
+            0, 0, 1, 0, 0,
+            0, 0, 0, 0, 0,
+        ], 5);
+
+        let symmetries = blinker.symmetries();
+        assert_eq!(4, symmetries.len());
+        for t in [Transform::Identity, Transform::Rotate180, Transform::FlipHorizontal, Transform::FlipVertical] {
+            assert!(symmetries.contains(t));
+        }
+    }
+
+    #[test]
+    fn glider_has_no_symmetry() {
+        let glider = create_board_x_by_x(vec![
+            0, 0, 0, 0, 0,
+            0, 0, 1, 0, 0,
+            0, 0, 0, 1, 0,
+            0, 1, 1, 1, 0,
+            0, 0, 0, 0, 0,
+        ], 5);
+        assert_eq!(SymmetrySet::empty().with(Transform::Identity), glider.symmetries());
+    }
+
+    #[test]
+    fn run_calls_back_each_step() {
+        let mut blinker = create_board_x_by_x(vec![
+            0, 0, 0, 0, 0,
+            0, 0, 1, 0, 0,
+            0, 0, 1, 0, 0,
+            0, 0, 1, 0, 0,
+            0, 0, 0, 0, 0,
+        ], 5);
+        let initial = blinker.clone();
+
+        let mut populations = vec![];
+        blinker.run(5, |board, generation| populations.push((generation, board.population())));
+
+        assert_eq!(vec![(1, 3), (2, 3), (3, 3), (4, 3), (5, 3)], populations);
+        // odd number of steps leaves the blinker in its other phase
+        assert_ne!(initial, blinker);
+    }
+
+    #[test]
+    fn blinker_checksum_trace_has_period_2() {
+        let blinker = create_board_x_by_x(vec![
+            0, 0, 0, 0, 0,
+            0, 0, 1, 0, 0,
+            0, 0, 1, 0, 0,
+            0, 0, 1, 0, 0,
+            0, 0, 0, 0, 0,
+        ], 5);
+
+        let trace = blinker.checksum_trace(4);
+        assert_eq!(4, trace.len());
+        assert_ne!(trace[0], trace[1]);
+        assert_eq!(trace[0], trace[2]);
+        assert_eq!(trace[1], trace[3]);
+        assert_eq!(blinker.checksum(), trace[1]);
+    }
+
+    #[test]
+    fn lone_cell_on_1x1_board_dies() {
+        let mut board = create_board_x_by_x(vec![1], 1);
+        assert_eq!(0, board.count_live_neighbors(0, 0));
+
+        board.advance();
+        assert_eq!(create_board_x_by_x(vec![0], 1), board);
+    }
+
+    #[test]
+    fn single_row_and_column_boards() {
+        let mut row = create_board_x_by_x(vec![1, 1, 1], 3);
+        row.advance();
+        assert_eq!(create_board_x_by_x(vec![0, 1, 0], 3), row);
+
+        let mut col = create_board_x_by_x(vec![1, 1, 1], 1);
+        col.advance();
+        assert_eq!(create_board_x_by_x(vec![0, 1, 0], 1), col);
+    }
+
+    #[test]
+    fn zero_dimension_boards_are_empty() {
+        for mut board in [Board::dead(0, 0), Board::dead(0, 5), Board::dead(5, 0), Board::random(0, 5)] {
+            assert_eq!((0, 0), (board.rows(), board.cols()));
+
+            board.advance();
+            board.advance_totalistic(|n| n == 3);
+            assert_eq!(Board::dead(0, 0), board);
+            assert_eq!("", board.to_string());
+        }
+    }
+
+    #[test]
+    fn set_each_draws_diagonal() {
+        let mut board = Board::dead(4, 4);
+        board.set_each(|row, col| row == col);
+
+        let expected = create_board_x_by_x(vec![
+            1, 0, 0, 0,
+            0, 1, 0, 0,
+            0, 0, 1, 0,
+            0, 0, 0, 1,
+        ], 4);
+        assert_eq!(expected, board);
+    }
+
+    #[test]
+    fn still_life_predicate() {
+        let block = create_board_x_by_x(vec![
+            0, 0, 0, 0,
+            0, 1, 1, 0,
+            0, 1, 1, 0,
+            0, 0, 0, 0,
+        ], 4);
+        let beehive = create_board_x_by_x(vec![
+            0, 0, 0, 0, 0, 0,
+            0, 0, 1, 1, 0, 0,
+            0, 1, 0, 0, 1, 0,
+            0, 0, 1, 1, 0, 0,
+            0, 0, 0, 0, 0, 0,
+        ], 6);
+        let blinker = create_board_x_by_x(vec![
+            0, 0, 0, 0, 0,
+            0, 0, 1, 0, 0,
+            0, 0, 1, 0, 0,
+            0, 0, 1, 0, 0,
+            0, 0, 0, 0, 0,
+        ], 5);
+
+        assert!(block.is_still_life());
+        assert!(beehive.is_still_life());
+        assert!(!blinker.is_still_life());
+    }
+
+    #[test]
+    fn oscillator_periods() {
+        assert_eq!(Some(2), blinker().is_oscillator(10));
+        assert_eq!(Some(2), toad().is_oscillator(10));
+        assert_eq!(None, blinker().is_oscillator(1));
+        assert_eq!(None, block().is_oscillator(10));
+    }
+
+    #[test]
+    fn combine_boards() {
+        let a = create_board_x_by_x(vec![
+            1, 1, 0,
+            1, 1, 0,
+            0, 0, 0,
+        ], 3);
+        let b = create_board_x_by_x(vec![
+            0, 0, 0,
+            0, 1, 1,
+            0, 1, 1,
+        ], 3);
+
+        let xor = create_board_x_by_x(vec![
+            1, 1, 0,
+            1, 0, 1,
+            0, 1, 1,
+        ], 3);
+        assert_eq!(Ok(xor), a.combine(&b, BoolOp::Xor));
+
+        let lone = create_board_x_by_x(vec![
+            0, 0, 0,
+            0, 0, 0,
+            1, 0, 0,
+        ], 3);
+        let or = create_board_x_by_x(vec![
+            1, 1, 0,
+            1, 1, 0,
+            1, 0, 0,
+        ], 3);
+        assert_eq!(Ok(or), a.combine(&lone, BoolOp::Or));
+
+        assert_eq!(
+            Err(ShapeError::Mismatch { expected: (3, 3), found: (4, 4) }),
+            a.combine(&block(), BoolOp::And)
+        );
+    }
+
+    #[test]
+    fn offset_neighbor_counts_match_ranges() {
+        // the original range-based implementation, kept here as a reference
+        fn count_with_ranges(board: &Board, row: usize, col: usize) -> usize {
+            let grid = &board.grid;
+            let valid_rows = row.saturating_sub(1)..=(row + 1).min(grid.rows() - 1);
+            let valid_cols = col.saturating_sub(1)..=(col + 1).min(grid.cols() - 1);
+            valid_rows.cartesian_product(valid_cols)
+                .filter(|i| i != &(row, col))
+                .filter(|&(r, c)| grid[r][c])
+                .count()
+        }
+
+        for (rows, cols) in [(20, 30), (1, 7), (7, 1), (1, 1)] {
+            let board = Board::random(rows, cols);
+            for (row, col) in (0..rows).cartesian_product(0..cols) {
+                assert_eq!(count_with_ranges(&board, row, col), board.count_live_neighbors(row, col));
+            }
+        }
+    }
+
+    #[test]
+    fn entropy_of_alive_fraction() {
+        assert_eq!(0.0, Board::dead(5, 5).entropy());
+        assert_eq!(0.0, Board::dead(0, 0).entropy());
+        assert_eq!(0.0, create_board_x_by_x(vec![1; 9], 3).entropy());
+
+        let mut checkerboard = Board::dead(6, 6);
+        checkerboard.set_each(|row, col| (row + col) % 2 == 0);
+        assert!((checkerboard.entropy() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn change_mask_marks_flipping_cells() {
+        let expected = create_board_x_by_x(vec![
+            0, 0, 0, 0, 0,
+            0, 0, 1, 0, 0,
+            0, 1, 0, 1, 0,
+            0, 0, 1, 0, 0,
+            0, 0, 0, 0, 0,
+        ], 5);
+        assert_eq!(expected, blinker().change_mask());
+        assert_eq!(Board::dead(4, 4), block().change_mask());
+    }
+
+    #[test]
+    fn glider_on_horizontal_cylinder() {
+        // a glider heading up and to the right, starting near the bottom right corner
+        let mut cells = Board::dead(12, 8);
+        cells.set_each(|row, col| matches!((row, col), (8, 5..=7) | (9, 7) | (10, 6)));
+        let mut cylinder = cells.clone().with_edges(EdgeModes::horizontal_cylinder());
+        let mut torus = cells.with_edges(EdgeModes::toroidal());
+
+        // after two full periods the glider has crossed the right edge onto the left
+        for _ in 0..8 {
+            cylinder.advance();
+            torus.advance();
+        }
+        assert_eq!(torus.grid, cylinder.grid);
+        assert_eq!(5, cylinder.population());
+        assert!((0..12).any(|row| cylinder.grid[row][0]));
+
+        // the torus wraps it onto the bottom rows too, but the cylinder's top edge is dead
+        for _ in 0..40 {
+            cylinder.advance();
+            torus.advance();
+        }
+        assert!((9..12).cartesian_product(0..8).any(|(row, col)| torus.grid[row][col]));
+        assert!((9..12).cartesian_product(0..8).all(|(row, col)| !cylinder.grid[row][col]));
+    }
+
+    #[test]
+    fn finds_isolated_gliders() {
+        let mut board = Board::dead(20, 20);
+        // south-east glider at (2, 2)
+        board.set_each(|row, col| matches!((row, col), (2, 3) | (3, 4) | (4, 2..=4)));
+        // a later phase of a north-west glider at (12, 13)
+        for (row, col) in [(12, 13), (12, 14), (13, 13), (13, 15), (14, 13)] {
+            board.set(row, col, true);
+        }
+
+        assert_eq!(
+            vec![(2, 2, Orientation::SouthEast), (12, 13, Orientation::NorthWest)],
+            board.find_gliders()
+        );
+
+        // one period later both have moved a cell diagonally in their reported direction
+        let mut later = board.clone();
+        (0..4).for_each(|_| later.advance());
+        assert_eq!(
+            vec![(3, 3, Orientation::SouthEast), (11, 12, Orientation::NorthWest)],
+            later.find_gliders()
+        );
+
+        // a neighboring cell means the first glider is no longer isolated
+        board.set(1, 1, true);
+        assert_eq!(vec![(12, 13, Orientation::NorthWest)], board.find_gliders());
+    }
+
+    #[test]
+    fn blinker_trace_alternates() {
+        let blinker = blinker();
+        let next = blinker.next_generation();
+
+        let trace = blinker.trace(5);
+        assert_eq!(6, trace.len());
+        for (i, board) in trace.iter().enumerate() {
+            assert_eq!(if i % 2 == 0 { &blinker } else { &next }, board);
+        }
+        assert_eq!(vec![blinker.clone()], blinker.trace(0));
+    }
+
+    #[test]
+    fn alive_edges_feed_the_border() {
+        let mut board = Board::dead(4, 4).with_edges(EdgeModes::uniform(EdgeMode::Alive));
+        // edge cells see 3 live cells beyond the edge, corners see 5
+        assert_eq!(3, board.count_live_neighbors(0, 1));
+        assert_eq!(5, board.count_live_neighbors(0, 0));
+        assert_eq!(0, board.count_live_neighbors(1, 1));
+
+        board.advance();
+        let expected = create_board_x_by_x(vec![
+            0, 1, 1, 0,
+            1, 0, 0, 1,
+            1, 0, 0, 1,
+            0, 1, 1, 0,
+        ], 4);
+        assert_eq!(expected.grid, board.grid);
+    }
+
+    #[test]
+    fn random_min_pop_reaches_floor() {
+        for seed in [0, 1, 42, 1234] {
+            for min in [0, 10, 50, 64] {
+                let board = Board::random_min_pop(8, 8, min, seed).unwrap();
+                assert!(board.population() >= min);
+                assert_eq!(board, Board::random_min_pop(8, 8, min, seed).unwrap());
+            }
+        }
+        assert_eq!(
+            Err(ShapeError::Capacity { capacity: 64, requested: 65 }),
+            Board::random_min_pop(8, 8, 65, 0)
+        );
+    }
+
+    #[test]
+    fn svg_has_rect_per_live_cell() {
+        let svg = blinker().to_svg(10);
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains("viewBox=\"0 0 50 50\""));
+        // one background rect plus the three live cells
+        assert_eq!(4, svg.matches("<rect").count());
+        assert!(svg.contains("<rect x=\"20\" y=\"10\" width=\"10\" height=\"10\""));
+    }
+
+    #[test]
+    fn population_deltas_track_growth() {
+        assert_eq!(vec![0; 6], blinker().population_deltas(6));
+
+        // a T-tetromino grows into a ring before collapsing toward a traffic light
+        let mut tee = Board::dead(9, 9);
+        tee.set_each(|row, col| matches!((row, col), (3, 3..=5) | (4, 4)));
+        let deltas = tee.population_deltas(3);
+        assert!(deltas[0] > 0);
+        assert!(deltas[1] < 0);
+        assert_eq!(vec![3, -1, 0], deltas);
+    }
+
+    #[test]
+    fn regional_rules_diverge() {
+        // a vertical blinker in each half of the board
+        let mut board = Board::dead(7, 12);
+        board.set_each(|row, col| (2..=4).contains(&row) && (col == 2 || col == 8));
+        let conway = board.next_generation();
+
+        let seeds: Rule = "B2/S".parse().unwrap();
+        board.advance_regional(&[(Rect::new(0, 6, 7, 6), seeds)], &Rule::conway());
+
+        // the left half keeps following Conway
+        for (row, col) in (0..7).cartesian_product(0..6) {
+            assert_eq!(conway.grid[row][col], board.grid[row][col]);
+        }
+        // under Seeds nothing survives, and only 4 cells see exactly two neighbors
+        let right = (0..7).cartesian_product(6..12)
+            .filter(|&(row, col)| board.grid[row][col])
+            .collect_vec();
+        assert_eq!(vec![(2, 7), (2, 9), (4, 7), (4, 9)], right);
+    }
+
+    #[test]
+    fn influence_region_is_chebyshev_square() {
+        let region = Board::dead(9, 9).influence_region(4, 4, 2);
+        assert_eq!(25, region.population());
+        assert_eq!(Some((2, 2, 5, 5)), region.bounding_box());
+
+        // clipped at the corner
+        assert_eq!(9, Board::dead(9, 9).influence_region(0, 0, 2).population());
+    }
+
+    #[test]
+    fn hamming_distance_counts_differences() {
+        let mut other = blinker();
+        assert_eq!(Ok(0), blinker().hamming_distance(&other));
+
+        other.set(0, 0, true);
+        assert_eq!(Ok(1), blinker().hamming_distance(&other));
+        assert_eq!(
+            Err(ShapeError::Mismatch { expected: (5, 5), found: (4, 4) }),
+            blinker().hamming_distance(&block())
+        );
+    }
+
+    #[test]
+    fn finds_predecessor_by_hill_climbing() {
+        for target in [block(), blinker()] {
+            let found = Board::search_predecessor(&target, 1, 10_000, 7).unwrap();
+            assert_eq!(target, found.next_generation());
+        }
+    }
+
+    #[test]
+    fn toroidal_bounding_box_wraps_seam() {
+        let mut board = Board::dead(8, 10);
+        board.set_each(|row, col| (4..=5).contains(&row) && matches!(col, 0 | 1 | 8 | 9));
+
+        assert_eq!(Some((4, 0, 2, 10)), board.bounding_box());
+        assert_eq!(Some((4, 8, 2, 4)), board.toroidal_bounding_box());
+
+        // patterns away from the seam get the ordinary box
+        assert_eq!(blinker().bounding_box(), blinker().toroidal_bounding_box());
+        assert_eq!(None, Board::dead(3, 3).toroidal_bounding_box());
+    }
+
+    #[test]
+    fn random_live_cell_picks_live_cells() {
+        let mut rng = StdRng::seed_from_u64(3);
+        assert_eq!(None, Board::dead(4, 4).random_live_cell(&mut rng));
+
+        let board = blinker();
+        let live = board.live_cells().collect_vec();
+        assert_eq!(vec![(1, 2), (2, 2), (3, 2)], live);
+        for _ in 0..20 {
+            assert!(live.contains(&board.random_live_cell(&mut rng).unwrap()));
+        }
+    }
+
+    #[test]
+    fn advance_growing_keeps_glider_off_edges() {
+        let mut board = Board::dead(5, 5);
+        board.set_each(|row, col| matches!((row, col), (1, 2) | (2, 3) | (3, 1..=3)));
+
+        for _ in 0..20 {
+            let size = (board.rows(), board.cols());
+            board.advance_growing(2);
+            assert!(board.rows() >= size.0 && board.cols() >= size.1);
+
+            assert_eq!(5, board.population());
+            let (rows, cols) = (board.rows(), board.cols());
+            assert!(board.live_cells().all(|(row, col)| row > 0 && col > 0 && row < rows - 1 && col < cols - 1));
+        }
+        assert!(board.rows() > 5 && board.cols() > 5);
+        assert_eq!(1, board.find_gliders().len());
+    }
+
+    #[test]
+    fn fold_generations_finds_peak_population() {
+        // an H shape that swells to 12 cells on its second step before dying out
+        let h = create_board_x_by_x(vec![
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 1, 0, 1, 0, 0, 0,
+            0, 0, 0, 1, 1, 1, 0, 0, 0,
+            0, 0, 0, 1, 0, 1, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ], 9);
+        let original = h.clone();
+
+        let max = h.fold_generations(8, 0, |max, board| max.max(board.population()));
+        assert_eq!(12, max);
+        assert_eq!(9, h.fold_generations(8, 0, |count, _| count + 1));
+        assert_eq!(original, h);
+    }
+
+    #[test]
+    fn trimmed_string_shows_block_and_margin() {
+        let mut board = Board::dead(30, 40);
+        board.set_each(|row, col| (10..=11).contains(&row) && (20..=21).contains(&col));
+
+        assert_eq!("    \n OO \n OO \n    \n", board.to_string_trimmed(1));
+        assert_eq!("OO\nOO\n", board.to_string_trimmed(0));
+        assert_eq!("(empty)\n", Board::dead(30, 40).to_string_trimmed(3));
+
+        // the margin is clipped at the edges of the board
+        assert_eq!(block().to_string(), block().to_string_trimmed(5));
+    }
+
+    #[test]
+    fn transition_counts_births_and_deaths() {
+        assert_eq!((2, 2), blinker().transition_counts());
+        assert_eq!((0, 0), block().transition_counts());
+    }
+
+    #[test]
+    fn garden_of_eden_detection() {
+        // a lone cell has no neighbors to be born or survive from, and no 3x3 board fills up
+        assert_eq!(Ok(true), create_board_x_by_x(vec![1], 1).is_garden_of_eden());
+        assert_eq!(Ok(true), create_board_x_by_x(vec![1; 9], 3).is_garden_of_eden());
+
+        // oscillators and still lifes are their own ancestors
+        assert_eq!(Ok(false), block().is_garden_of_eden());
+        assert_eq!(Ok(false), blinker().is_garden_of_eden());
+        assert_eq!(Ok(false), blinker().with_edges(EdgeModes::toroidal()).is_garden_of_eden());
+        for predecessor in blinker().predecessors(5).unwrap() {
+            assert_eq!(blinker(), predecessor.next_generation());
+        }
+
+        // wide boards are searched transposed, keeping their own shape and edges
+        let wide = blinker().next_generation().crop(1, 0, 3, 5).with_edges(EdgeModes::horizontal_cylinder());
+        let predecessors = wide.predecessors(3).unwrap();
+        assert!(!predecessors.is_empty());
+        for predecessor in predecessors {
+            assert_eq!(wide.edges(), predecessor.edges());
+            assert_eq!(wide, predecessor.next_generation());
+        }
+        assert_eq!(Ok(false), Board::dead(1, MAX_PREDECESSOR_CELLS).is_garden_of_eden());
+        assert_eq!(
+            Err(ShapeError::SearchSize { limit: MAX_PREDECESSOR_CELLS, cells: 64 }),
+            Board::dead(1, 64).is_garden_of_eden()
+        );
+        assert!(Board::dead(7, 7).predecessors(1).is_err());
+    }
+
+    #[test]
+    fn blinker_heatmap() {
+        let heat = blinker().heatmap(4);
+        assert_eq!((5, 5), (heat.len(), heat[0].len()));
+
+        // the center is always alive, the arms alternate between vertical and horizontal
+        assert_eq!(5, heat[2][2]);
+        assert_eq!(3, heat[1][2]);
+        assert_eq!(2, heat[2][1]);
+        assert_eq!(0, heat[0][0]);
+    }
+
+    #[test]
+    fn pads_to_powers_of_two() {
+        let board = create_board_x_by_x(vec![
+            1, 0, 0, 0, 1,
+            0, 1, 1, 1, 0,
+            1, 0, 0, 0, 1,
+        ], 5);
+        let padded = board.pad_to_pow2();
+
+        assert_eq!((4, 8), (padded.rows(), padded.cols()));
+        assert_eq!(board, padded.crop(0, 1, 3, 5));
+        assert_eq!(board.population(), padded.population());
+
+        assert_eq!(block(), block().pad_to_pow2());
+    }
+
+    #[test]
+    fn measures_spaceship_velocity() {
+        let mut glider = Board::dead(10, 10);
+        glider.set_each(|row, col| matches!((row, col), (1, 2) | (2, 3) | (3, 1..=3)));
+        assert_eq!(Some((1, 1, 4)), glider.measure_velocity(10));
+
+        let lwss: Board = "
+            0000000000000
+            0000000000000
+            0000000000000
+            0000000000000
+            0000000000000
+            0000001001000
+            0000010000000
+            0000010001000
+            0000011110000
+            0000000000000
+            0000000000000
+            0000000000000
+            0000000000000
+        ".parse().unwrap();
+        assert_eq!(Some((0, -2, 4)), lwss.measure_velocity(10));
+
+        assert_eq!(Some((0, 0, 1)), block().measure_velocity(10));
+        assert_eq!(Some((0, 0, 2)), blinker().measure_velocity(10));
+        assert_eq!(None, Board::dead(3, 3).measure_velocity(10));
+    }
+
+    #[test]
+    fn squares_up_trimmed_pattern() {
+        let mut board = Board::dead(10, 10);
+        board.set_each(|row, col| (3..=4).contains(&row) && (2..=5).contains(&col));
+
+        let square = board.to_square();
+        let expected = create_board_x_by_x(vec![
+            0, 0, 0, 0,
+            1, 1, 1, 1,
+            1, 1, 1, 1,
+            0, 0, 0, 0,
+        ], 4);
+        assert_eq!(expected, square);
+        assert_eq!((3, 3), (blinker().to_square().rows(), blinker().to_square().cols()));
+    }
+
+    #[test]
+    fn verifies_minimal_oscillator_period() {
+        assert!(blinker().verify_oscillator(2));
+        assert!(!blinker().verify_oscillator(3));
+        assert!(!blinker().verify_oscillator(4));
+        assert!(!blinker().verify_oscillator(1));
+        assert!(block().verify_oscillator(1));
+        assert!(!block().verify_oscillator(0));
+    }
+
+    #[test]
+    fn randomizes_only_the_region() {
+        let mut rng = StdRng::seed_from_u64(9);
+        let mut board = Board::dead(10, 10);
+        board.set_each(|row, col| (row + col) % 3 == 0);
+        let original = board.clone();
+
+        board.randomize_region(&mut rng, 2, 3, 4, 5, 0.5);
+        let region = Rect::new(2, 3, 4, 5);
+        for (row, col) in (0..10).cartesian_product(0..10).filter(|&(r, c)| !region.contains(r, c)) {
+            assert_eq!(original.grid[row][col], board.grid[row][col]);
+        }
+        assert_ne!(original, board);
+
+        // clipped at the bottom right corner
+        board.randomize_region(&mut rng, 8, 8, 5, 5, 1.0);
+        assert!((8..10).cartesian_product(8..10).all(|(row, col)| board.grid[row][col]));
+    }
+
+    #[test]
+    fn equivalence_up_to_symmetry() {
+        let glider: Board = ".O.\n..O\nOOO\n".parse().unwrap();
+        let mut elsewhere = Board::dead(8, 8);
+        elsewhere.stamp(&glider.transform(Transform::FlipHorizontal), 4, 2);
+
+        assert!(glider.is_equivalent(&elsewhere));
+        for t in Transform::ALL {
+            assert!(glider.is_equivalent(&glider.transform(t)));
+        }
+        assert!(!glider.is_equivalent(&blinker()));
+        assert!(blinker().is_equivalent(&blinker().next_generation()));
+    }
+
+    #[test]
+    fn steps_to_reach_population() {
+        // a T-tetromino goes 4, 7, 6, 6, 8 cells over its first steps
+        let mut tee = Board::dead(9, 9);
+        tee.set_each(|row, col| matches!((row, col), (3, 3..=5) | (4, 4)));
+
+        assert_eq!(Some(0), tee.steps_to_population(4, 10));
+        assert_eq!(Some(1), tee.steps_to_population(7, 10));
+        assert_eq!(Some(4), tee.steps_to_population(8, 10));
+        assert_eq!(None, tee.steps_to_population(8, 3));
+        assert_eq!(None, tee.steps_to_population(100, 10));
+    }
+
+    #[test]
+    fn toggles_keep_neighbor_counts_consistent() {
+        let mut rng = StdRng::seed_from_u64(150);
+        let edges = [
+            EdgeModes::default(),
+            EdgeModes::toroidal(),
+            EdgeModes::uniform(EdgeMode::Alive),
+            EdgeModes::horizontal_cylinder(),
+        ];
+        for edges in edges {
+            let mut board = Board::dead(6, 7).with_edges(edges);
+            for _ in 0..200 {
+                board.toggle(rng.gen_range(0..6), rng.gen_range(0..7));
+                assert_eq!(board.neighbor_counts(), board.counts.iter().copied().collect_vec());
+            }
+            let mut expected = board.clone();
+            expected.recount();
+            assert_eq!(expected.next_generation(), board.next_generation());
+        }
+    }
+
+    #[test]
+    fn advance_capped_stops_on_population_cap() {
+        // the T-tetromino reaches 7 cells on its first step and 8 on its fourth
+        let mut tee = Board::dead(9, 9);
+        tee.set_each(|row, col| matches!((row, col), (3, 3..=5) | (4, 4)));
+        let mut capped = tee.clone();
+        assert_eq!(Err(1), capped.advance_capped(10, 6));
+        assert_eq!(7, capped.population());
+        assert_eq!(Err(4), tee.clone().advance_capped(10, 7));
+        assert_eq!(Err(0), tee.clone().advance_capped(10, 3));
+
+        let mut block = Board::dead(4, 4);
+        block.set_each(|row, col| (1..3).contains(&row) && (1..3).contains(&col));
+        assert_eq!(Ok(10), block.advance_capped(10, 4));
+        assert_eq!(4, block.population());
+    }
+
+    #[test]
+    fn boundary_of_filled_block_is_its_ring() {
+        let mut board = Board::dead(5, 5);
+        board.set_each(|row, col| (1..4).contains(&row) && (1..4).contains(&col));
+
+        let ring = (1..4).cartesian_product(1..4).filter(|&cell| cell != (2, 2)).collect_vec();
+        assert_eq!(ring, board.boundary());
+
+        let mut full = Board::dead(3, 3);
+        full.set_each(|_, _| true);
+        assert_eq!(8, full.boundary().len());
+        assert_eq!(full.boundary(), full.clone().with_edges(EdgeModes::toroidal()).boundary());
+        assert_eq!(full.boundary(), full.with_edges(EdgeModes::uniform(EdgeMode::Alive)).boundary());
+    }
+
+    #[test]
+    fn convex_hull_of_l_shape() {
+        let mut board = Board::dead(5, 5);
+        board.set_each(|row, col| (row == 4 && col <= 2) || (col == 0 && row >= 1));
+        assert_eq!(vec![(1, 0), (4, 0), (4, 2)], board.convex_hull());
+
+        board.set(1, 3, true);
+        assert_eq!(vec![(1, 0), (4, 0), (4, 2), (1, 3)], board.convex_hull());
+
+        let mut line = Board::dead(1, 5);
+        line.set_each(|_, _| true);
+        assert_eq!(vec![(0, 0), (0, 4)], line.convex_hull());
+        assert!(line.crop(0, 0, 1, 2).convex_hull().is_empty());
+    }
+
+    #[test]
+    fn traces_match_until_edges_matter() {
+        let glider: Board = ".O......\n..O.....\nOOO.....\n........\n........\n........\n".parse().unwrap();
+        assert!(glider.traces_match(&glider.clone(), 20));
+
+        let wrapped = glider.clone().with_edges(EdgeModes::toroidal());
+        assert!(glider.traces_match(&wrapped, 5));
+        assert!(!glider.traces_match(&wrapped, 20));
+        assert!(!glider.traces_match(&glider.next_generation(), 0));
+
+        // the allocation-free simulation takes the same path as `advance`
+        let mut sim = Simulation::new(&wrapped);
+        sim.step_n(20);
+        let mut advanced = wrapped.clone();
+        advanced.advance_n(20);
+        assert!(advanced.traces_match(&sim.to_board(), 20));
+    }
+
+    #[test]
+    fn build_grid_rejects_ragged_cells() {
+        assert_eq!(
+            Err(ShapeError::Ragged { cells: 7, cols: 3 }),
+            build_grid(vec![false; 7], 3).map(|grid| grid.size())
+        );
+        assert_eq!(
+            Err(ShapeError::Ragged { cells: 2, cols: 0 }),
+            build_grid(vec![true; 2], 0).map(|grid| grid.size())
+        );
+        assert_eq!((0, 0), build_grid(Vec::<bool>::new(), 4).unwrap().size());
+        assert_eq!((2, 3), build_grid(vec![false; 6], 3).unwrap().size());
+    }
+
+    #[test]
+    fn clusters_connect_diagonally() {
+        let board: Board = "OO...\n.O...\n..O..\n....O\n....O\n".parse().unwrap();
+        assert_eq!(
+            vec![vec![(0, 0), (0, 1), (1, 1), (2, 2)], vec![(3, 4), (4, 4)]],
+            board.clusters()
+        );
+        assert!(Board::dead(3, 3).clusters().is_empty());
+    }
+
+    #[test]
+    fn advance_into_fills_buffer() {
+        let board = Board::random_min_pop(7, 9, 20, 158).unwrap();
+        let mut out = vec![true; 63];
+        board.advance_into(&mut out).unwrap();
+        assert_eq!(board.next_generation().grid.into_vec(), out);
+
+        let mut short = vec![false; 62];
+        assert_eq!(Err(ShapeError::Length { expected: 63, found: 62 }), board.advance_into(&mut short));
+    }
+
+    #[test]
+    fn tiles_block_pattern() {
+        let mut cell = Board::dead(2, 2);
+        cell.set(0, 0, true);
+        let tiled = cell.tile(3, 3);
+
+        assert_eq!((6, 6), (tiled.rows(), tiled.cols()));
+        assert_eq!(9, tiled.population());
+        assert_eq!(Some(true), tiled.get(4, 2));
+        assert_eq!(Some(false), tiled.get(5, 3));
+        assert_eq!((0, 0), (cell.tile(0, 2).rows(), cell.tile(0, 2).cols()));
+    }
+
+    #[test]
+    fn padded_string_centers_board() {
+        let mut board = Board::dead(3, 3);
+        board.set_each(|row, col| row == 0 || col == 2);
+
+        assert_eq!(Ok(".....\n.###.\n...#.\n...#.\n.....\n".to_string()), board.to_string_padded(5, 5, '.', '#'));
+        assert_eq!(Ok("###.\n..#.\n..#.\n....\n".to_string()), board.to_string_padded(4, 4, '.', '#'));
+        assert_eq!(
+            Err(ShapeError::TooLarge { limit: (2, 5), found: (3, 3) }),
+            board.to_string_padded(5, 2, '.', '#')
+        );
+    }
+
+    #[test]
+    fn steps_to_extinction_of_dying_and_stable_patterns() {
+        // a diagonal of three cells shrinks to its center, then vanishes
+        let mut diagonal = Board::dead(5, 5);
+        diagonal.set_each(|row, col| row == col && (1..4).contains(&row));
+        assert_eq!(Some(2), diagonal.steps_to_extinction(10));
+        assert_eq!(None, diagonal.steps_to_extinction(1));
+        assert_eq!(Some(0), Board::dead(3, 3).steps_to_extinction(0));
+
+        assert_eq!(None, block().steps_to_extinction(50));
+    }
+
+    #[test]
+    fn blinker_phases() {
+        let horizontal = create_board_x_by_x(vec![
+            0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0,
+            0, 1, 1, 1, 0,
+            0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0,
+        ], 5);
+
+        assert_eq!(blinker(), blinker().phase(0));
+        assert_eq!(horizontal, blinker().phase(1));
+        assert_eq!(blinker(), blinker().phase(2));
+    }
+
+    #[test]
+    fn quiescent_block_but_not_blinker() {
+        assert!(block().is_quiescent());
+        assert!(Board::dead(3, 3).is_quiescent());
+        assert!(!blinker().is_quiescent());
+    }
+
+    #[test]
+    fn ages_of_block_and_blinker() {
+        let ages = block().age_grid(6);
+        for (row, col) in block().live_cells() {
+            assert_eq!(7, ages[row][col]);
+        }
+        assert_eq!(Some(&0), ages[0].first());
+
+        // the blinker's center never dies, while its arms are reborn every other step
+        let ages = blinker().age_grid(3);
+        assert_eq!(4, ages[2][2]);
+        assert_eq!(1, ages[2][1]);
+        assert_eq!(0, ages[1][2]);
+    }
+
+    #[test]
+    fn convolved_neighbor_counts_match() {
+        let edges = [
+            EdgeModes::default(),
+            EdgeModes::toroidal(),
+            EdgeModes::uniform(EdgeMode::Alive),
+            EdgeModes { horizontal: EdgeMode::Alive, vertical: EdgeMode::Dead },
+            EdgeModes { horizontal: EdgeMode::Toroidal, vertical: EdgeMode::Alive },
+        ];
+        for (seed, edges) in edges.into_iter().enumerate() {
+            let board = Board::random_min_pop(9, 13, 0, seed as u64).unwrap().with_edges(edges);
+            assert_eq!(board.neighbor_counts(), board.neighbor_counts_conv());
+        }
+    }
+
+    /// Compares the two ways of counting neighbors. Run with `cargo test -- --ignored --nocapture`.
+    #[test]
+    #[ignore]
+    fn bench_neighbor_counts() {
+        let board = Board::random_min_pop(256, 256, 0, 166).unwrap();
+        for (name, count) in [
+            ("naive", Board::neighbor_counts as fn(&Board) -> Vec<u8>),
+            ("convolution", Board::neighbor_counts_conv),
+        ] {
+            let start = std::time::Instant::now();
+            for _ in 0..20 {
+                std::hint::black_box(count(&board));
+            }
+            println!("{name}: {:?} per board", start.elapsed() / 20);
+        }
+    }
+
+    #[test]
+    fn flood_fill_stays_in_pocket() {
+        let mut board: Board = "\
+            ......\n\
+            .OOOO.\n\
+            .O..O.\n\
+            .O.OO.\n\
+            .OOO..\n"
+            .parse()
+            .unwrap();
+        let before = board.clone();
+        board.flood_fill(2, 2, true);
+
+        let filled = [(2, 2), (2, 3), (3, 2)];
+        for (row, col) in (0..5).cartesian_product(0..6) {
+            let expected = before.grid[row][col] || filled.contains(&(row, col));
+            assert_eq!(expected, board.grid[row][col], "({row}, {col})");
+        }
+        assert_eq!(board.neighbor_counts(), board.counts.iter().copied().collect_vec());
+
+        board.flood_fill(0, 0, true);
+        assert_eq!(30, board.population());
+        board.flood_fill(0, 0, false);
+        assert_eq!(0, board.population());
+    }
+
+    #[test]
+    fn blinker_cell_histories() {
+        let blinker = blinker();
+        assert_eq!(vec![true; 5], blinker.cell_history(2, 2, 4));
+        assert_eq!(vec![true, false, true, false, true], blinker.cell_history(1, 2, 4));
+        assert_eq!(vec![false, true, false], blinker.cell_history(2, 1, 2));
+        assert_eq!(vec![false], blinker.cell_history(0, 0, 0));
+    }
+
+    #[test]
+    fn weighted_random_follows_gradient() {
+        let mut rng = StdRng::seed_from_u64(170);
+        let gradient = vec![(0..40).map(|col| col as f64 / 39.0).collect_vec(); 30];
+        let board = Board::random_weighted(&mut rng, &gradient).unwrap();
+
+        assert_eq!((30, 40), (board.rows(), board.cols()));
+        let live_in = |cols: std::ops::Range<usize>| board.live_cells().filter(|(_, col)| cols.contains(col)).count();
+        assert!(live_in(0..10) * 3 < live_in(30..40));
+        assert!((0..30).all(|row| !board.grid[row][0] && board.grid[row][39]));
+
+        assert_eq!(
+            Err(ShapeError::RowLength { row: 1, expected: 3, found: 2 }),
+            Board::random_weighted(&mut rng, &[vec![0.5; 3], vec![0.5; 2], vec![0.5; 1]])
+        );
+        assert_eq!(
+            Err(ShapeError::RowLength { row: 2, expected: 2, found: 3 }),
+            Board::random_weighted(&mut rng, &[vec![0.5; 2], vec![0.5; 2], vec![0.5; 3]])
+        );
+        assert_eq!(
+            Err(ShapeError::Weight { row: 1, col: 0 }),
+            Board::random_weighted(&mut rng, &[vec![0.5; 2], vec![1.5, 0.5]])
+        );
+        assert_eq!(Ok(Board::dead(0, 0)), Board::random_weighted(&mut rng, &[]));
+    }
+
+    #[test]
+    fn settles_into_block_and_blinker() {
+        // three cells of a block fill in the fourth
+        let mut corner = Board::dead(6, 6);
+        corner.set_each(|row, col| matches!((row, col), (2, 2) | (2, 3) | (3, 2)));
+        let (ash, steps) = corner.settle(10).unwrap();
+        assert_eq!(1, steps);
+        assert_eq!(4, ash.population());
+        assert!(ash.is_still_life());
+
+        assert_eq!(Some((blinker(), 0)), blinker().settle(2));
+        assert_eq!(None, blinker().settle(1));
+
+        let glider: Board = ".O......\n..O.....\nOOO.....\n........\n........\n........\n".parse().unwrap();
+        assert_eq!(None, glider.settle(8));
+    }
+
+    #[test]
+    fn windowed_activity_fades_as_pattern_dies() {
+        // the ends of the diagonal die first, then the center
+        let mut diagonal = Board::dead(5, 5);
+        diagonal.set_each(|row, col| row == col && (1..4).contains(&row));
+
+        assert_eq!(vec![2, 1, 0, 0], diagonal.windowed_activity(4, 1));
+        assert_eq!(vec![2, 3, 3, 1, 0, 0], diagonal.windowed_activity(6, 3));
+        assert_eq!(vec![0, 0], diagonal.windowed_activity(2, 0));
+
+        // a blinker's four arm cells change every step, while its center never does
+        assert_eq!(vec![4; 5], blinker().windowed_activity(5, 2));
+    }
+
+    #[test]
+    fn glider_returns_around_torus() {
+        for n in [5, 6, 8] {
+            let mut board = Board::dead(n, n);
+            board.stamp(&".O.\n..O\nOOO\n".parse().unwrap(), 0, 0);
+            assert_eq!(Some(4 * n), board.toroidal_return_period(100));
+            assert_eq!(None, board.toroidal_return_period(4 * n - 1));
+        }
+        assert_eq!(Some(2), blinker().toroidal_return_period(10));
+        assert_eq!(Some(1), block().toroidal_return_period(10));
+    }
+
+    #[test]
+    fn blinker_stable_cells() {
+        let stable = blinker().stable_cells(3);
+        let arms = [(1, 2), (3, 2), (2, 1), (2, 3)];
+        for (row, col) in (0..5).cartesian_product(0..5) {
+            assert_eq!(!arms.contains(&(row, col)), stable.grid[row][col], "({row}, {col})");
+        }
+        assert_eq!(25, blinker().stable_cells(0).population());
+        assert_eq!(16, block().stable_cells(10).population());
+    }
+
+    #[test]
+    fn population_bound_covers_glider() {
+        let mut board = Board::dead(12, 12);
+        board.stamp(&".O.\n..O\nOOO\n".parse().unwrap(), 0, 0);
+
+        // the glider's 3x3 box grows by one cell on each side per step, clipped at the corner
+        assert_eq!(5, board.max_possible_population(0));
+        assert_eq!(16, board.max_possible_population(1));
+        assert_eq!(144, board.max_possible_population(20));
+        for steps in 0..8 {
+            assert!(board.phase(steps).population() <= board.max_possible_population(steps));
+        }
+        assert_eq!(0, Board::dead(5, 5).max_possible_population(3));
+    }
+
+    #[test]
+    fn samples_blinker_every_other_step() {
+        let blinker = blinker();
+        let samples = blinker.run_sampled(10, 2);
+        assert_eq!(6, samples.len());
+        assert!(samples.iter().all(|sample| *sample == blinker));
+
+        let odd = blinker.run_sampled(10, 3);
+        assert_eq!(vec![blinker.clone(), blinker.next_generation(), blinker.clone(), blinker.next_generation()], odd);
+        assert_eq!(vec![blinker.clone()], blinker.run_sampled(10, 0));
+    }
+
+    #[test]
+    fn run_summary_of_rows() {
+        let board: Board = "...OO.....\nOOOOOOOOOO\n..O.......\n".parse().unwrap();
+        assert_eq!("3d 2a 5d\n10a\n2d 1a 7d\n", board.to_run_summary());
+        assert_eq!("", Board::dead(0, 0).to_run_summary());
+    }
+
+    #[test]
+    fn skeleton_of_filled_rectangle() {
+        let mut board = Board::dead(7, 11);
+        board.set_each(|row, col| (1..6).contains(&row) && (1..10).contains(&col));
+        let skeleton = board.skeletonize();
+
+        // a wide rectangle thins to a line along its middle row
+        assert_eq!(vec![(3, 3), (3, 4), (3, 5), (3, 6)], skeleton.live_cells().collect_vec());
+        assert!(skeleton.population() < board.population());
+        assert_eq!(skeleton, skeleton.skeletonize());
+
+        let mut square = Board::dead(9, 9);
+        square.set_each(|row, col| (1..8).contains(&row) && (1..8).contains(&col));
+        assert_eq!(vec![(4, 4)], square.skeletonize().live_cells().collect_vec());
+    }
+
+    #[test]
+    fn densest_window_finds_corner_cluster() {
+        let mut board = Board::dead(10, 12);
+        board.set_each(|row, col| (row >= 7 && col >= 9) || (row, col) == (0, 0));
+
+        assert_eq!(Some((7, 9, 9)), board.densest_window(3, 3));
+        assert_eq!(Some((6, 8, 9)), board.densest_window(4, 4));
+        assert_eq!(Some((0, 0, 0)), Board::dead(3, 3).densest_window(2, 2));
+        assert_eq!(Some((0, 0, 10)), board.densest_window(10, 12));
+        assert_eq!(None, board.densest_window(11, 2));
+        assert_eq!(None, board.densest_window(0, 2));
+    }
+
+    #[test]
+    fn solid_block_is_more_compact_than_scattered_cells() {
+        let mut solid = Board::dead(6, 6);
+        solid.set_each(|row, col| (1..5).contains(&row) && (1..5).contains(&col));
+        let mut scattered = Board::dead(6, 6);
+        scattered.set_each(|row, col| row % 3 == 1 && col % 3 == 1);
+
+        assert_eq!(Some(0.75), solid.compactness());
+        assert_eq!(Some(1.0), scattered.compactness());
+        assert_eq!(None, Board::dead(6, 6).compactness());
+    }
+
+    #[test]
+    fn ash_of_settling_soup() {
+        // the corner fills in to a block, while the blinker keeps oscillating
+        let soup: Board = "\
+            ..........\n\
+            .OO.......\n\
+            .O........\n\
+            ..........\n\
+            .....OOO..\n\
+            ..........\n"
+            .parse()
+            .unwrap();
+        let ash = soup.ash(10).unwrap();
+
+        let block: Board = "OO\nOO\n".parse().unwrap();
+        assert_eq!(2, ash.len());
+        assert_eq!(block, ash[0]);
+        assert!(ash[1].is_equivalent(&"OOO".parse().unwrap()));
+
+        let glider: Board = ".O......\n..O.....\nOOO.....\n........\n........\n........\n".parse().unwrap();
+        assert_eq!(None, glider.ash(5));
+    }
+
+    #[test]
+    fn first_recurrences() {
+        assert_eq!(Some((0, 2)), blinker().first_recurrence(10));
+        assert_eq!(Some((0, 1)), block().first_recurrence(10));
+
+        // the diagonal shrinks, then stays empty from step 2
+        let mut diagonal = Board::dead(5, 5);
+        diagonal.set_each(|row, col| row == col && (1..4).contains(&row));
+        assert_eq!(None, diagonal.first_recurrence(2));
+        assert_eq!(Some((2, 3)), diagonal.first_recurrence(10));
+    }
+
+    #[test]
+    fn one_cell_difference_diverges() {
+        let soup = Board::random_min_pop(20, 20, 0, 190).unwrap();
+        let mut nudged = soup.clone();
+        nudged.toggle(10, 10);
+
+        let distances = soup.divergence(&nudged, 15).unwrap();
+        assert_eq!(16, distances.len());
+        assert_eq!(1, distances[0]);
+        assert!(distances[15] > distances[0]);
+
+        assert_eq!(Ok(vec![0; 6]), soup.divergence(&soup, 5));
+        assert!(soup.divergence(&Board::dead(3, 3), 5).is_err());
+    }
+
+    #[test]
+    fn contact_sheet_of_four_boards() {
+        let boards = [0, 1, 2, 3].map(|n| {
+            let mut board = Board::dead(3, 3);
+            board.set(n % 3, n % 3, true);
+            board
+        });
+        let sheet = Board::contact_sheet(&boards, 2, 1, '.', 'O');
+
+        let lines = sheet.lines().collect_vec();
+        assert_eq!(7, lines.len());
+        assert!(lines.iter().all(|line| line.chars().count() == 7));
+        assert_eq!("O......", lines[0]);
+        assert_eq!(".......", lines[3]);
+        assert_eq!("..O....", lines[6]);
+        assert_eq!("....O..", lines[4]);
+        assert_eq!(".....O.", lines[1]);
+
+        // three boards leave the last slot blank, and a smaller board is centered
+        let mut mixed = boards[..2].to_vec();
+        mixed.push("O".parse().unwrap());
+        let lines = Board::contact_sheet(&mixed, 2, 2, ' ', '#').lines().map(str::to_string).collect_vec();
+        assert_eq!(8, lines.len());
+        assert_eq!(" #      ", lines[6]);
+        assert_eq!("", Board::contact_sheet(&[], 3, 1, '.', 'O'));
+    }
+
+    #[test]
+    fn definitely_dead_cells_of_sparse_board() {
+        let mut board = Board::dead(6, 6);
+        for (row, col) in [(0, 0), (3, 3), (3, 4)] {
+            board.set(row, col, true);
+        }
+        let mask = board.definitely_dead_next();
+
+        // both the lonely live cells and every dead cell near them can't be alive next step
+        assert_eq!(36, mask.population());
+        let next = board.next_generation();
+        assert!(mask.live_cells().all(|(row, col)| !next.grid[row][col]));
+
+        // a blinker's ends each see one neighbor and will die, but the cells beside its center
+        // see three and will be born
+        let mask = blinker().definitely_dead_next();
+        assert!(mask.grid[1][2] && mask.grid[3][2] && !mask.grid[2][2]);
+        assert!(!mask.grid[2][1] && !mask.grid[2][3]);
+        assert!(mask.grid[0][0]);
+
+        // with births on 2, the pair's dead neighbors might come alive
+        let seeds = board.clone().with_rule("B2/S".parse().unwrap()).definitely_dead_next();
+        assert!(!seeds.grid[2][3] && seeds.grid[5][0]);
+    }
+
+    #[test]
+    fn mutation_flips_about_rate_cells() {
+        let mut rng = StdRng::seed_from_u64(193);
+        let original = Board::random_min_pop(40, 50, 0, 193).unwrap();
+
+        let mut mutated = original.clone();
+        mutated.mutate(&mut rng, 0.1);
+        let flipped = original.hamming_distance(&mutated).unwrap();
+        assert!((150..250).contains(&flipped), "{flipped} of 2000 cells flipped");
+
+        let mut unchanged = original.clone();
+        unchanged.mutate(&mut rng, 0.0);
+        assert_eq!(original, unchanged);
+
+        let mut inverted = original.clone();
+        inverted.mutate(&mut rng, 1.0);
+        assert_eq!(Ok(2000), original.hamming_distance(&inverted));
+    }
+
+    #[test]
+    fn growth_rates() {
+        assert_eq!(0.0, blinker().growth_rate(10));
+        assert_eq!(0.0, block().growth_rate(10));
+
+        // the T-tetromino goes from 4 cells to 8 in 4 steps
+        let mut tee = Board::dead(9, 9);
+        tee.set_each(|row, col| matches!((row, col), (3, 3..=5) | (4, 4)));
+        assert_eq!(1.0, tee.growth_rate(4));
+        assert_eq!(0.0, tee.growth_rate(0));
+    }
+
+    #[test]
+    fn blinker_shape_descriptors() {
+        let (height, width, vertical) = blinker().shape_descriptor().unwrap();
+        assert_eq!((3, 1), (height, width));
+        let (height, width, horizontal) = blinker().next_generation().shape_descriptor().unwrap();
+        assert_eq!((1, 3), (height, width));
+        assert_eq!(1.0, vertical * horizontal);
+        assert_eq!(3.0, horizontal);
+
+        assert_eq!(None, Board::dead(4, 4).shape_descriptor());
+    }
+
+    #[test]
+    fn replaying_deltas_reproduces_run() {
+        let soup = Board::random_min_pop(15, 15, 0, 198).unwrap();
+        let deltas = soup.delta_stream(12);
+        assert_eq!(12, deltas.len());
+
+        let mut replayed = soup.clone();
+        for (step, changes) in deltas.iter().enumerate() {
+            for &(row, col, alive) in changes {
+                replayed.set(row, col, alive);
+            }
+            assert_eq!(soup.phase(step + 1), replayed);
+        }
+        assert_eq!(vec![(1, 2, false), (2, 1, true), (2, 3, true), (3, 2, false)], blinker().delta_stream(1)[0]);
+    }
+
+    #[test]
+    fn checks_stamp_placement() {
+        let board = Board::dead(6, 8);
+        let glider: Board = ".O.\n..O\nOOO\n".parse().unwrap();
+
+        assert!(board.can_stamp(&glider, 0, 0));
+        assert!(board.can_stamp(&glider, 3, 5));
+        assert!(!board.can_stamp(&glider, 3, 6));
+        assert!(!board.can_stamp(&glider, 4, 0));
+        assert!(!board.can_stamp(&glider, 0, usize::MAX));
+    }
+
+    #[test]
+    fn blinker_diff_against_future() {
+        assert_eq!(0, blinker().diff_future(2).population());
+        assert_eq!(blinker().change_mask(), blinker().diff_future(1));
+        assert_eq!(4, blinker().diff_future(3).population());
+    }
+
+    #[test]
+    fn classifies_lifespans() {
+        let domino: Board = "....\n.OO.\n....".parse().unwrap();
+        assert_eq!(Lifespan::Extinct(1), domino.classify(10));
+        assert_eq!(Lifespan::Extinct(0), Board::dead(3, 3).classify(10));
+
+        let corner: Board = "....\n.OO.\n.O..\n....".parse().unwrap();
+        assert_eq!(Lifespan::Stabilized { step: 1, period: 1 }, corner.classify(10));
+
+        let mut board = Board::dead(5, 8);
+        board.stamp(&"OOO".parse().unwrap(), 2, 1);
+        board.set(0, 7, true);
+        assert_eq!(Lifespan::Stabilized { step: 1, period: 2 }, board.classify(10));
+        assert_eq!(Lifespan::Active, board.classify(0));
+    }
+
+    #[test]
+    fn renders_cursor_over_cells() {
+        let blinker = blinker();
+        assert_eq!(".....\n..O..\n..#..\n..O..\n.....\n", blinker.to_string_with_cursor((2, 2), '.', 'O', '#'));
+        assert_eq!("#....\n..O..\n..O..\n..O..\n.....\n", blinker.to_string_with_cursor((0, 0), '.', 'O', '#'));
+        assert_eq!(blinker.to_string_padded(5, 5, '.', 'O').unwrap(), blinker.to_string_with_cursor((5, 0), '.', 'O', '#'));
+    }
+
+    #[test]
+    fn symmetrizes_across_axes() {
+        let glider: Board = ".O..\n..O.\nOOO.".parse().unwrap();
+        let mut board = glider.clone();
+        board.symmetrize(Axis::Horizontal);
+        assert_eq!(board, board.transform(Transform::FlipHorizontal));
+        assert_eq!(Ok(0), glider.combine(&board, BoolOp::AndNot).map(|extra| extra.population()));
+        assert_eq!(".OO.\n.OO.\nOOOO".parse::<Board>().unwrap(), board);
+
+        let mut board = glider.clone();
+        board.symmetrize(Axis::Both);
+        assert_eq!(board, board.transform(Transform::FlipHorizontal));
+        assert_eq!(board, board.transform(Transform::FlipVertical));
+        assert_ne!(glider, board.transform(Transform::FlipVertical));
+    }
+
+    #[test]
+    fn advances_blinker_to_checksum() {
+        let target = blinker().checksum_trace(1)[0];
+        let mut board = blinker();
+        assert_eq!(Some(1), board.advance_until_checksum(target, 10));
+        assert_eq!(blinker().next_generation(), board);
+        assert_eq!(Some(0), board.advance_until_checksum(target, 10));
+
+        let mut board = blinker();
+        assert_eq!(None, board.advance_until_checksum(Board::dead(5, 5).checksum(), 3));
+        assert_eq!(blinker().phase(3), board);
+    }
+
+    #[test]
+    fn injected_glider_annihilates_block() {
+        for orientation in [Orientation::NorthEast, Orientation::NorthWest, Orientation::SouthEast, Orientation::SouthWest] {
+            let mut board = Board::dead(7, 7);
+            board.inject_glider(2, 2, orientation);
+            assert_eq!(vec![(2, 2, orientation)], board.find_gliders());
+        }
+
+        let mut board = Board::dead(16, 16);
+        board.stamp(&block().trim(), 8, 8);
+        board.inject_glider(2, 3, Orientation::SouthEast);
+        board.advance_n(9);
+        assert_eq!(11, board.population());
+        board.advance_n(3);
+        assert_eq!(0, board.population());
+    }
+
+    #[test]
+    fn glider_activates_cell() {
+        let mut board = Board::dead(10, 10);
+        board.inject_glider(0, 0, Orientation::SouthEast);
+        // the glider moves one cell diagonally every 4 steps
+        assert_eq!(Some(8), board.time_to_activate(4, 4, 30));
+        assert_eq!(Some(16), board.time_to_activate(6, 6, 30));
+        assert_eq!(None, board.time_to_activate(6, 6, 15));
+        assert_eq!(Some(0), board.time_to_activate(0, 1, 30));
+        assert_eq!(None, board.time_to_activate(9, 0, 30));
+        assert_eq!(None, board.time_to_activate(10, 10, 30));
+    }
+
+    #[test]
+    fn glider_drifts_and_block_stays() {
+        let mut board = Board::dead(20, 20);
+        board.inject_glider(0, 0, Orientation::SouthEast);
+        let (rows, cols) = board.activity_drift(16);
+        assert!(rows > 0.0 && cols > 0.0);
+        assert!((rows - 0.25).abs() < 1e-9 && (cols - 0.25).abs() < 1e-9);
+
+        assert_eq!((0.0, 0.0), block().activity_drift(10));
+        assert_eq!((0.0, 0.0), board.activity_drift(0));
+        assert_eq!((0.0, 0.0), Board::dead(4, 4).activity_drift(5));
     }
 }
@@ -0,0 +1,30 @@
+use rayon::prelude::*;
+
+use crate::Board;
+
+/// Advances each of `boards` `steps` times, spreading the boards across threads, and returns
+/// the final states in the same order.
+pub fn simulate_many(boards: Vec<Board>, steps: usize) -> Vec<Board> {
+    boards.into_par_iter()
+        .map(|mut board| {
+            board.advance_n(steps);
+            board
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_sequential_runs() {
+        let boards = (0..8)
+            .map(|seed| Board::random_min_pop(16, 20, 0, seed).unwrap())
+            .collect::<Vec<_>>();
+        let sequential = boards.iter().map(|board| board.phase(25)).collect::<Vec<_>>();
+
+        assert_eq!(sequential, simulate_many(boards, 25));
+        assert!(simulate_many(vec![], 5).is_empty());
+    }
+}
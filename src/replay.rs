@@ -0,0 +1,61 @@
+use crate::Board;
+
+/// A recorded run that can be scrubbed to any generation without re-simulating.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Replay {
+    frames: Vec<Board>,
+}
+
+impl Replay {
+    /// Records `board` and its next `steps` generations (see [`Board::trace`]).
+    pub fn record(board: &Board, steps: usize) -> Self {
+        Replay { frames: board.trace(steps) }
+    }
+
+    /// Returns generation `n` of the run, where 0 is the starting board.
+    pub fn frame(&self, n: usize) -> Option<&Board> {
+        self.frames.get(n)
+    }
+
+    /// The number of recorded frames, including the starting board.
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// The last recorded generation.
+    pub fn last(&self) -> Option<&Board> {
+        self.frames.last()
+    }
+
+    pub fn frames(&self) -> &[Board] {
+        &self.frames
+    }
+}
+
+impl From<Vec<Board>> for Replay {
+    fn from(frames: Vec<Board>) -> Self {
+        Replay { frames }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scrubs_toad_replay() {
+        let toad: Board = "000000\n000000\n001110\n011100\n000000\n000000\n".parse().unwrap();
+        let replay = Replay::record(&toad, 10);
+
+        assert_eq!(11, replay.len());
+        assert_eq!(Some(&toad), replay.frame(0));
+        assert_eq!(Some(&toad.next_generation()), replay.frame(7));
+        assert_eq!(Some(&toad), replay.frame(4));
+        assert_eq!(Some(&toad), replay.last());
+        assert_eq!(None, replay.frame(11));
+    }
+}
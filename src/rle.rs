@@ -0,0 +1,80 @@
+use std::fmt::Display;
+
+use crate::rule::{Rule, RuleError};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RleError {
+    /// No header line was found before the end of the input.
+    MissingHeader,
+    /// A required header field (`x` or `y`) was absent.
+    MissingField(&'static str),
+    /// A header field couldn't be parsed.
+    InvalidField(String),
+    /// The `rule` field wasn't a valid rule string.
+    Rule(RuleError),
+}
+
+impl Display for RleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RleError::MissingHeader => write!(f, "missing RLE header line"),
+            RleError::MissingField(field) => write!(f, "RLE header is missing `{field}`"),
+            RleError::InvalidField(field) => write!(f, "invalid RLE header field {field:?}"),
+            RleError::Rule(e) => write!(f, "invalid RLE rule: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for RleError {}
+
+impl From<RuleError> for RleError {
+    fn from(e: RuleError) -> Self {
+        RleError::Rule(e)
+    }
+}
+
+/// Parses just the header line of an RLE pattern, returning the declared `(x, y, rule)`, where
+/// `x` is the width and `y` the height.
+///
+/// Leading `#` comment lines are skipped, and a missing `rule` field defaults to Conway.
+pub fn parse_rle_header(input: &str) -> Result<(usize, usize, Rule), RleError> {
+    let header = input.lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty() && !line.starts_with('#'))
+        .ok_or(RleError::MissingHeader)?;
+
+    let (mut x, mut y, mut rule) = (None, None, Rule::conway());
+    for field in header.split(',') {
+        let (key, value) = field.split_once('=')
+            .ok_or_else(|| RleError::InvalidField(field.trim().to_string()))?;
+        let (key, value) = (key.trim(), value.trim());
+        let parse_size = || value.parse::<usize>().map_err(|_| RleError::InvalidField(field.trim().to_string()));
+        match key {
+            "x" => x = Some(parse_size()?),
+            "y" => y = Some(parse_size()?),
+            "rule" => rule = value.parse()?,
+            // unknown fields are ignored for forward compatibility
+            _ => {}
+        }
+    }
+
+    Ok((x.ok_or(RleError::MissingField("x"))?, y.ok_or(RleError::MissingField("y"))?, rule))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_header_with_rule() {
+        let input = "#N HighLife replicator\n#C a comment\nx = 5, y = 4, rule = B36/S23\n2o$o2bo!";
+        assert_eq!(Ok((5, 4, Rule::new(&[3, 6], &[2, 3]))), parse_rle_header(input));
+    }
+
+    #[test]
+    fn header_defaults_to_conway() {
+        assert_eq!(Ok((3, 3, Rule::conway())), parse_rle_header("x = 3, y = 3\nbo$2bo$3o!"));
+        assert_eq!(Err(RleError::MissingField("y")), parse_rle_header("x = 3"));
+        assert_eq!(Err(RleError::MissingHeader), parse_rle_header("#C only comments"));
+    }
+}
@@ -0,0 +1,175 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// An outer-totalistic birth/survival rule, such as Conway's `B3/S23`.
+///
+/// A dead cell is born if its live neighbor count is in the birth set, and a live cell survives
+/// if its count is in the survival set. All other cells are dead on the next step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rule {
+    birth: [bool; 9],
+    survival: [bool; 9],
+}
+
+impl Default for Rule {
+    fn default() -> Self {
+        Rule::conway()
+    }
+}
+
+impl Rule {
+    /// Creates a rule from lists of neighbor counts. Counts above 8 are ignored.
+    pub fn new(birth: &[usize], survival: &[usize]) -> Self {
+        let mut rule = Rule { birth: [false; 9], survival: [false; 9] };
+        birth.iter().filter(|&&n| n <= 8).for_each(|&n| rule.birth[n] = true);
+        survival.iter().filter(|&&n| n <= 8).for_each(|&n| rule.survival[n] = true);
+        rule
+    }
+
+    /// Conway's Game of Life, `B3/S23`.
+    pub fn conway() -> Self {
+        Rule::new(&[3], &[2, 3])
+    }
+
+    /// Decodes a Wolfram-style outer-totalistic code, as Golly accepts for two-state rules: bit
+    /// `2 * n + 1` of `code` is set if a live cell with `n` live neighbors survives, and bit
+    /// `2 * n` if a dead one is born. Conway's Life is code 224.
+    ///
+    /// Only 2 `states` are supported, and the code must fit in the 18 bits those describe.
+    pub fn from_totalistic(code: u32, states: u8) -> Result<Self, RuleError> {
+        if states != 2 {
+            return Err(RuleError::UnsupportedStates(states));
+        }
+        if code >= 1 << 18 {
+            return Err(RuleError::CodeOutOfRange(code));
+        }
+        let mut rule = Rule { birth: [false; 9], survival: [false; 9] };
+        for n in 0..=8 {
+            rule.birth[n] = code & (1 << (2 * n)) != 0;
+            rule.survival[n] = code & (1 << (2 * n + 1)) != 0;
+        }
+        Ok(rule)
+    }
+
+    /// Returns whether a cell is alive next step given its current state and live neighbor count.
+    pub fn next_state(&self, alive: bool, neighbors: usize) -> bool {
+        let table = if alive { &self.survival } else { &self.birth };
+        table.get(neighbors).copied().unwrap_or(false)
+    }
+
+    pub fn births(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..=8).filter(|&n| self.birth[n])
+    }
+
+    pub fn survivals(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..=8).filter(|&n| self.survival[n])
+    }
+}
+
+impl Display for Rule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "B")?;
+        for n in self.births() {
+            write!(f, "{n}")?;
+        }
+        write!(f, "/S")?;
+        for n in self.survivals() {
+            write!(f, "{n}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RuleError {
+    /// The rule wasn't of the form `B.../S...` or `S.../B...`.
+    Malformed(String),
+    /// A neighbor count wasn't a digit from 0 to 8.
+    InvalidCount(char),
+    /// A rule was asked to have a number of cell states other than 2.
+    UnsupportedStates(u8),
+    /// A totalistic code had bits set past the 18 a two-state rule uses.
+    CodeOutOfRange(u32),
+}
+
+impl Display for RuleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RuleError::Malformed(rule) => write!(f, "malformed rule string {rule:?}"),
+            RuleError::InvalidCount(c) => write!(f, "invalid neighbor count {c:?}"),
+            RuleError::UnsupportedStates(states) => write!(f, "rules with {states} states aren't supported"),
+            RuleError::CodeOutOfRange(code) => write!(f, "totalistic code {code} is out of range"),
+        }
+    }
+}
+
+impl std::error::Error for RuleError {}
+
+impl FromStr for Rule {
+    type Err = RuleError;
+
+    /// Parses a rule in `B3/S23` notation. The halves may appear in either order and the
+    /// letters are case-insensitive.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let malformed = || RuleError::Malformed(s.to_string());
+        let (first, second) = s.trim().split_once('/').ok_or_else(malformed)?;
+
+        let mut birth = None;
+        let mut survival = None;
+        for part in [first, second] {
+            let mut chars = part.chars();
+            let slot = match chars.next().map(|c| c.to_ascii_uppercase()) {
+                Some('B') => &mut birth,
+                Some('S') => &mut survival,
+                _ => return Err(malformed()),
+            };
+            if slot.is_some() {
+                return Err(malformed());
+            }
+            let counts = chars
+                .map(|c| match c.to_digit(10) {
+                    Some(n) if n <= 8 => Ok(n as usize),
+                    _ => Err(RuleError::InvalidCount(c)),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            *slot = Some(counts);
+        }
+
+        match (birth, survival) {
+            (Some(birth), Some(survival)) => Ok(Rule::new(&birth, &survival)),
+            _ => Err(malformed()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_displays_rules() {
+        let highlife: Rule = "B36/S23".parse().unwrap();
+        assert_eq!(Rule::new(&[3, 6], &[2, 3]), highlife);
+        assert_eq!("B36/S23", highlife.to_string());
+        assert_eq!(Rule::conway(), "s23/b3".parse().unwrap());
+
+        assert_eq!(Err(RuleError::InvalidCount('9')), "B9/S23".parse::<Rule>());
+        assert!(matches!("B3S23".parse::<Rule>(), Err(RuleError::Malformed(_))));
+    }
+
+    #[test]
+    fn decodes_totalistic_codes() {
+        let conway = Rule::from_totalistic(224, 2).unwrap();
+        assert_eq!("B3/S23".parse::<Rule>().unwrap(), conway);
+
+        let blinker: crate::Board = ".....\n..O..\n..O..\n..O..\n.....\n".parse().unwrap();
+        let mut decoded = blinker.clone();
+        decoded.advance_regional(&[], &conway);
+        assert_eq!(blinker.next_generation(), decoded);
+
+        // HighLife adds birth on 6 neighbors, bit 12
+        assert_eq!(Rule::new(&[3, 6], &[2, 3]), Rule::from_totalistic(224 | 1 << 12, 2).unwrap());
+        assert_eq!(Err(RuleError::UnsupportedStates(3)), Rule::from_totalistic(224, 3));
+        assert_eq!(Err(RuleError::CodeOutOfRange(1 << 18)), Rule::from_totalistic(1 << 18, 2));
+    }
+}
@@ -0,0 +1,63 @@
+use crate::Board;
+
+/// A set of patterns to be stamped together onto one board.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Scene {
+    patterns: Vec<(Board, usize, usize)>,
+}
+
+impl Scene {
+    pub fn new() -> Self {
+        Scene::default()
+    }
+
+    /// Adds `pattern` with its top-left corner at `(row, col)`.
+    pub fn add(&mut self, pattern: Board, row: usize, col: usize) -> &mut Self {
+        self.patterns.push((pattern, row, col));
+        self
+    }
+
+    /// Stamps every added pattern, in order, onto a dead `rows` x `cols` board. Parts of
+    /// patterns falling outside the board are clipped.
+    pub fn render(self, rows: usize, cols: usize) -> Board {
+        let mut board = Board::dead(rows, cols);
+        for (pattern, row, col) in &self.patterns {
+            board.stamp(pattern, *row, *col);
+        }
+        board
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn composes_gliders_and_block() {
+        let glider: Board = ".O.\n..O\nOOO\n".parse().unwrap();
+        let block: Board = "OO\nOO\n".parse().unwrap();
+
+        let mut scene = Scene::new();
+        scene.add(glider.clone(), 0, 0)
+            .add(glider, 5, 7)
+            .add(block, 8, 0);
+        let board = scene.render(10, 10);
+
+        assert_eq!(14, board.population());
+        assert_eq!(
+            vec![
+                (0, 1), (1, 2), (2, 0), (2, 1), (2, 2),
+                (5, 8), (6, 9), (7, 7), (7, 8), (7, 9),
+                (8, 0), (8, 1), (9, 0), (9, 1),
+            ],
+            board.live_cells().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn clips_patterns_past_the_edge() {
+        let mut scene = Scene::new();
+        scene.add("OO\nOO\n".parse().unwrap(), 2, 2);
+        assert_eq!(vec![(2, 2)], scene.render(3, 3).live_cells().collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,108 @@
+use grid::Grid;
+
+use crate::{BoolOp, Board, ShapeError};
+
+/// A reversible second-order version of Life, which remembers the previous generation as well as
+/// the current one.
+///
+/// Each step applies the board's rule to the current generation and XORs the result with the
+/// previous one. Since XOR undoes itself, [`SecondOrderBoard::rewind`] can recover the previous
+/// generation exactly. Edges follow the boards' edge modes, toroidal edges being the usual
+/// choice.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecondOrderBoard {
+    previous: Board,
+    current: Board,
+}
+
+impl SecondOrderBoard {
+    /// Starts from `current` with a dead previous generation.
+    pub fn new(current: Board) -> Self {
+        let previous = current.with_grid(Grid::new(current.rows(), current.cols()));
+        SecondOrderBoard { previous, current }
+    }
+
+    /// Starts from two explicit generations, which must be the same size. The previous
+    /// generation takes on the current one's edges and rule, so both directions step alike.
+    pub fn from_states(previous: Board, current: Board) -> Result<Self, ShapeError> {
+        previous.check_same_size(&current)?;
+        let previous = previous.with_edges(current.edges()).with_rule(current.rule());
+        Ok(SecondOrderBoard { previous, current })
+    }
+
+    pub fn current(&self) -> &Board {
+        &self.current
+    }
+
+    pub fn previous(&self) -> &Board {
+        &self.previous
+    }
+
+    /// Advances one step, so the current generation becomes the previous one.
+    pub fn advance(&mut self) {
+        let next = self.current.next_generation().combine(&self.previous, BoolOp::Xor)
+            .expect("both generations are the same size");
+        self.previous = std::mem::replace(&mut self.current, next);
+    }
+
+    /// Exactly undoes one [`SecondOrderBoard::advance`], which also lets the rule run backward
+    /// from the starting state.
+    pub fn rewind(&mut self) {
+        let before = self.previous.next_generation().combine(&self.current, BoolOp::Xor)
+            .expect("both generations are the same size");
+        self.current = std::mem::replace(&mut self.previous, before);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EdgeModes;
+
+    #[test]
+    fn rewinding_undoes_advancing() {
+        let soup = Board::random_min_pop(10, 12, 30, 183).unwrap().with_edges(EdgeModes::toroidal());
+        let start = SecondOrderBoard::new(soup);
+        let mut board = start.clone();
+
+        for _ in 0..25 {
+            board.advance();
+        }
+        assert_ne!(start, board);
+        for _ in 0..25 {
+            board.rewind();
+        }
+        assert_eq!(start, board);
+    }
+
+    #[test]
+    fn rewinds_past_the_start() {
+        let mut board = SecondOrderBoard::new(Board::random_min_pop(6, 6, 10, 7).unwrap());
+        let start = board.clone();
+        board.rewind();
+        board.rewind();
+        board.advance();
+        board.advance();
+        assert_eq!(start, board);
+
+        assert!(SecondOrderBoard::from_states(Board::dead(2, 3), Board::dead(3, 2)).is_err());
+    }
+
+    #[test]
+    fn explicit_states_share_current_settings() {
+        let previous = Board::random_min_pop(8, 8, 20, 11).unwrap().with_rule("B36/S23".parse().unwrap());
+        let current = Board::random_min_pop(8, 8, 20, 12).unwrap().with_edges(EdgeModes::toroidal());
+        let start = SecondOrderBoard::from_states(previous, current).unwrap();
+        assert_eq!(start.current().edges(), start.previous().edges());
+        assert_eq!(start.current().rule(), start.previous().rule());
+
+        let mut board = start.clone();
+        for _ in 0..10 {
+            board.advance();
+        }
+        for _ in 0..10 {
+            board.rewind();
+        }
+        assert_eq!(start, board);
+    }
+}
@@ -0,0 +1,117 @@
+use crate::{build_grid, Board, EdgeModes, Rule};
+
+/// A simulation that steps between two preallocated buffers, so [`Simulation::step`]
+/// never touches the heap once the simulation is built.
+///
+/// Cells are stored row-major and follow the edge modes and rule of the board it was built
+/// from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Simulation {
+    front: Vec<bool>,
+    back: Vec<bool>,
+    rows: usize,
+    cols: usize,
+    edges: EdgeModes,
+    rule: Rule,
+    generation: u64,
+}
+
+impl Simulation {
+    /// Starts a simulation from `board`, allocating both buffers up front.
+    pub fn new(board: &Board) -> Self {
+        let (rows, cols) = (board.rows(), board.cols());
+        let front = board.grid.iter().copied().collect();
+        Simulation {
+            front,
+            back: vec![false; rows * cols],
+            rows,
+            cols,
+            edges: board.edges(),
+            rule: board.rule(),
+            generation: 0,
+        }
+    }
+
+    /// Advances one generation by filling the back buffer from the front and swapping them.
+    pub fn step(&mut self) {
+        for index in 0..self.front.len() {
+            let (row, col) = (index / self.cols, index % self.cols);
+            self.back[index] = self.rule.next_state(self.front[index], self.count_live_neighbors(row, col));
+        }
+        std::mem::swap(&mut self.front, &mut self.back);
+        self.generation += 1;
+    }
+
+    /// Advances `n` generations.
+    pub fn step_n(&mut self, n: usize) {
+        for _ in 0..n {
+            self.step();
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// The number of steps taken since the simulation was built.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Returns whether the cell at `(row, col)` is alive, or `None` if it's off the board.
+    pub fn get(&self, row: usize, col: usize) -> Option<bool> {
+        (row < self.rows && col < self.cols).then(|| self.front[row * self.cols + col])
+    }
+
+    /// The current cells in row-major order.
+    pub fn cells(&self) -> &[bool] {
+        &self.front
+    }
+
+    pub fn population(&self) -> usize {
+        self.front.iter().filter(|&&cell| cell).count()
+    }
+
+    /// Copies the current generation out into a new board with the same edges.
+    pub fn to_board(&self) -> Board {
+        let grid = build_grid(self.front.clone(), self.cols).expect("the buffer holds every row");
+        Board::from_grid(grid).with_edges(self.edges).with_rule(self.rule)
+    }
+
+    fn count_live_neighbors(&self, row: usize, col: usize) -> usize {
+        self.edges.count_live_neighbors(row, col, (self.rows, self.cols), |r, c| self.front[r * self.cols + c])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EdgeMode;
+
+    #[test]
+    fn steps_match_board_and_reuse_buffers() {
+        for edges in [EdgeModes::default(), EdgeModes::toroidal(), EdgeModes::uniform(EdgeMode::Alive)] {
+            let mut board = Board::random_min_pop(12, 15, 40, 153).unwrap().with_edges(edges);
+            let mut sim = Simulation::new(&board);
+            let buffers = |sim: &Simulation| {
+                let mut ptrs = [sim.front.as_ptr(), sim.back.as_ptr()];
+                ptrs.sort();
+                (ptrs, sim.front.capacity(), sim.back.capacity())
+            };
+            let before = buffers(&sim);
+
+            for _ in 0..100 {
+                sim.step();
+                board.advance();
+                assert_eq!(board, sim.to_board());
+                assert_eq!(before, buffers(&sim));
+            }
+            assert_eq!(100, sim.generation());
+            assert_eq!(board.population(), sim.population());
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use std::collections::{HashMap, HashSet};
+
+use grid::Grid;
+
+use crate::{Board, EdgeModes, Rule, ShapeError, NEIGHBOR_OFFSETS};
+
+/// A board on the unbounded plane that stores only its live cells, as signed `(row, col)`
+/// coordinates.
+///
+/// Since the plane has no edges, rules that give birth with no live neighbors can't be
+/// followed, and only cells next to a live cell are ever born.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SparseBoard {
+    live: HashSet<(i64, i64)>,
+    rule: Rule,
+}
+
+impl SparseBoard {
+    /// Creates an empty board following Conway's rule.
+    pub fn new() -> Self {
+        SparseBoard::default()
+    }
+
+    /// Copies the live cells of `board`, keeping each at the same `(row, col)`, along with its
+    /// rule. The board's edges are dropped.
+    pub fn from_dense(board: &Board) -> Self {
+        let live = board.live_cells().map(|(row, col)| (row as i64, col as i64)).collect();
+        SparseBoard { live, rule: board.rule() }
+    }
+
+    /// Returns the board with its rule replaced.
+    pub fn with_rule(mut self, rule: Rule) -> Self {
+        self.rule = rule;
+        self
+    }
+
+    pub fn rule(&self) -> Rule {
+        self.rule
+    }
+
+    pub fn get(&self, row: i64, col: i64) -> bool {
+        self.live.contains(&(row, col))
+    }
+
+    pub fn set(&mut self, row: i64, col: i64, alive: bool) {
+        if alive {
+            self.live.insert((row, col));
+        } else {
+            self.live.remove(&(row, col));
+        }
+    }
+
+    pub fn population(&self) -> usize {
+        self.live.len()
+    }
+
+    /// The coordinates of every live cell, in no particular order.
+    pub fn live_cells(&self) -> impl Iterator<Item = (i64, i64)> + '_ {
+        self.live.iter().copied()
+    }
+
+    /// Advances the board one generation.
+    pub fn advance(&mut self) {
+        // every live cell gets an entry, so one with no live neighbors can still survive
+        let mut counts: HashMap<(i64, i64), usize> = self.live.iter().map(|&cell| (cell, 0)).collect();
+        for &(row, col) in &self.live {
+            for (dr, dc) in NEIGHBOR_OFFSETS {
+                *counts.entry((row + dr as i64, col + dc as i64)).or_default() += 1;
+            }
+        }
+        self.live = counts.into_iter()
+            .filter(|&(cell, count)| self.rule.next_state(self.live.contains(&cell), count))
+            .map(|(cell, _)| cell)
+            .collect();
+    }
+}
+
+impl Board {
+    /// Lays the live cells of `sparse` out on a `rows` x `cols` board with dead edges and the
+    /// sparse board's rule, putting the sparse cell at `origin` in the top left corner.
+    ///
+    /// Fails if any live cell falls outside the board.
+    pub fn from_sparse(sparse: &SparseBoard, rows: usize, cols: usize, origin: (i64, i64)) -> Result<Board, ShapeError> {
+        let mut grid = Grid::init(rows, cols, false);
+        for (row, col) in sparse.live_cells() {
+            let (r, c) = (row - origin.0, col - origin.1);
+            let index = usize::try_from(r).ok().zip(usize::try_from(c).ok());
+            match index.and_then(|(r, c)| grid.get_mut(r, c)) {
+                Some(cell) => *cell = true,
+                None => return Err(ShapeError::OutOfBounds { row, col }),
+            }
+        }
+        Ok(Board::with_parts(grid, EdgeModes::default(), sparse.rule))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+
+    #[test]
+    fn glider_round_trips_through_sparse() {
+        let glider: Board = "....\n..O.\n...O\n.OOO".parse().unwrap();
+        let sparse = SparseBoard::from_dense(&glider);
+        assert_eq!(5, sparse.population());
+        assert!(sparse.get(1, 2));
+        assert_eq!(Ok(glider.clone()), Board::from_sparse(&sparse, 4, 4, (0, 0)));
+
+        let shifted = Board::from_sparse(&sparse, 3, 3, (1, 1)).unwrap();
+        assert_eq!(glider.trim(), shifted);
+        assert_eq!(Err(ShapeError::OutOfBounds { row: 1, col: 2 }), Board::from_sparse(&sparse, 3, 3, (2, 1)));
+    }
+
+    #[test]
+    fn advances_across_the_plane() {
+        let glider: Board = ".O.\n..O\nOOO".parse().unwrap();
+        let mut sparse = SparseBoard::from_dense(&glider);
+        for _ in 0..4 {
+            sparse.advance();
+        }
+        assert_eq!(Ok(glider), Board::from_sparse(&sparse, 3, 3, (1, 1)));
+
+        let mut beyond = SparseBoard::new();
+        for row in -1..=1 {
+            beyond.set(row, 0, true);
+        }
+        beyond.advance();
+        assert_eq!(vec![(0, -1), (0, 0), (0, 1)], beyond.live_cells().sorted().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn isolated_cell_survives_under_s0() {
+        let mut sparse = SparseBoard::new().with_rule("B3/S023".parse().unwrap());
+        sparse.set(5, -5, true);
+        sparse.advance();
+        assert_eq!(vec![(5, -5)], sparse.live_cells().collect::<Vec<_>>());
+
+        let mut dense = Board::from_sparse(&sparse, 3, 3, (4, -6)).unwrap();
+        dense.advance();
+        assert_eq!(Ok(dense), Board::from_sparse(&sparse, 3, 3, (4, -6)));
+    }
+}
@@ -0,0 +1,135 @@
+use std::io::BufRead;
+use std::str::FromStr;
+
+use crate::{build_grid, Board, ParseError};
+
+/// Separates patterns in the input to [`Board::from_multi`].
+const DELIMITER: &str = "---";
+
+impl FromStr for Board {
+    type Err = ParseError;
+
+    /// Parses a board with one row per line.
+    ///
+    /// `1`, `O` and `*` are live cells and `0` and `.` are dead, so both plain 0/1 grids and
+    /// the plaintext `.cells` format are accepted. Spaces and tabs are ignored, lines starting
+    /// with `!` are comments, and rows shorter than the longest are padded with dead cells.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_lines(&s.lines().collect::<Vec<_>>(), 1)
+    }
+}
+
+impl Board {
+    /// Parses several patterns separated by `---` lines, each in the format accepted by
+    /// [`Board::from_str`](std::str::FromStr). Sections holding no rows are skipped.
+    pub fn from_multi(input: &str) -> Result<Vec<Board>, ParseError> {
+        let lines = input.lines().collect::<Vec<_>>();
+        let mut boards = vec![];
+        let mut start = 0;
+        for end in (0..=lines.len()).filter(|&i| i == lines.len() || lines[i].trim() == DELIMITER) {
+            match parse_lines(&lines[start..end], start + 1) {
+                Ok(board) => boards.push(board),
+                Err(ParseError::Empty) => {}
+                Err(e) => return Err(e),
+            }
+            start = end + 1;
+        }
+        if boards.is_empty() {
+            return Err(ParseError::Empty);
+        }
+        Ok(boards)
+    }
+
+    /// Reads a board in the format accepted by [`Board::from_str`](std::str::FromStr) from
+    /// `reader`, stopping at the end of input or the first blank line after the pattern starts.
+    /// Anything after that line is left unread.
+    pub fn from_reader<R: BufRead>(reader: R) -> Result<Board, ParseError> {
+        let mut lines = vec![];
+        let mut skipped = 0;
+        for line in reader.lines() {
+            let line = line.map_err(|e| ParseError::Io(e.to_string()))?;
+            match (line.trim().is_empty(), lines.is_empty()) {
+                (true, true) => skipped += 1,
+                (true, false) => break,
+                (false, _) => lines.push(line),
+            }
+        }
+        parse_lines(&lines.iter().map(String::as_str).collect::<Vec<_>>(), skipped + 1)
+    }
+}
+
+/// Parses `lines` as a single board, numbering them from `first_line` in errors.
+fn parse_lines(lines: &[&str], first_line: usize) -> Result<Board, ParseError> {
+    let mut rows = vec![];
+    for (i, line) in lines.iter().enumerate() {
+        if line.starts_with('!') {
+            continue;
+        }
+        let row = line.chars()
+            .filter(|c| !matches!(c, ' ' | '\t' | '\r'))
+            .map(|c| match c {
+                '1' | 'O' | '*' => Ok(true),
+                '0' | '.' => Ok(false),
+                _ => Err(ParseError::Syntax { line: first_line + i, reason: format!("unexpected cell {c:?}") }),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        rows.push(row);
+    }
+
+    // blank lines only count as rows in the middle of a pattern
+    let first = rows.iter().position(|row| !row.is_empty()).ok_or(ParseError::Empty)?;
+    let last = rows.iter().rposition(|row| !row.is_empty()).unwrap_or(first);
+    let rows = &rows[first..=last];
+
+    let cols = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let cells = rows.iter()
+        .flat_map(|row| row.iter().copied().chain(std::iter::repeat(false)).take(cols))
+        .collect();
+    Ok(Board::from_grid(build_grid(cells, cols).expect("every row is padded to the widest")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_01_and_plaintext() {
+        let from_01: Board = "010\n001\n111\n".parse().unwrap();
+        let plaintext: Board = "!Name: Glider\n.O\n..O\nOOO\n".parse().unwrap();
+        assert_eq!(from_01, plaintext);
+        assert_eq!((3, 3), (from_01.rows(), from_01.cols()));
+        assert_eq!(5, from_01.population());
+
+        assert_eq!(Err(ParseError::Empty), "!just a comment\n\n".parse::<Board>());
+        assert!(matches!("01\n0x\n".parse::<Board>(), Err(ParseError::Syntax { line: 2, .. })));
+    }
+
+    #[test]
+    fn parses_multiple_patterns() {
+        let input = "!Block\nOO\nOO\n---\n0 1 0\n0 1 0\n0 1 0\n---\n";
+        let boards = Board::from_multi(input).unwrap();
+
+        assert_eq!(2, boards.len());
+        assert_eq!("OO\nOO\n".parse::<Board>().unwrap(), boards[0]);
+        assert!(boards[0].is_still_life());
+        assert_eq!(Some(2), boards[1].is_oscillator(2));
+
+        // errors report lines of the whole input
+        assert!(matches!(Board::from_multi("OO\n---\nO?\n"), Err(ParseError::Syntax { line: 3, .. })));
+    }
+
+    #[test]
+    fn reads_until_blank_line() {
+        let mut input = std::io::Cursor::new("\n010\n001\n111\n\n111\n");
+        let glider = Board::from_reader(&mut input).unwrap();
+        assert_eq!(".O.\n..O\nOOO\n".parse::<Board>().unwrap(), glider);
+
+        // the rest of the input is still there for the next read
+        assert_eq!("111\n".parse::<Board>().unwrap(), Board::from_reader(&mut input).unwrap());
+        assert_eq!(Err(ParseError::Empty), Board::from_reader(&mut input));
+        assert_eq!(
+            Err(ParseError::Syntax { line: 3, reason: "unexpected cell 'x'".to_string() }),
+            Board::from_reader("\n01\nx1\n".as_bytes())
+        );
+    }
+}
@@ -0,0 +1,69 @@
+use std::time::Duration;
+
+use crate::Board;
+
+/// Drives a board from an external clock, such as a game loop's frame times, advancing one
+/// generation per `interval` of accumulated time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimedSim {
+    board: Board,
+    interval: Duration,
+    accumulated: Duration,
+}
+
+impl TimedSim {
+    /// # Panics
+    ///
+    /// Panics if `interval` is zero.
+    pub fn new(board: Board, interval: Duration) -> Self {
+        assert!(!interval.is_zero(), "the step interval must be positive");
+        TimedSim { board, interval, accumulated: Duration::ZERO }
+    }
+
+    /// Adds `dt` to the accumulated time and advances the board once for each whole interval
+    /// in it, returning how many steps were taken. Leftover time carries over to the next call.
+    pub fn update(&mut self, dt: Duration) -> usize {
+        self.accumulated += dt;
+        let mut steps = 0;
+        while self.accumulated >= self.interval {
+            self.board.advance();
+            self.accumulated -= self.interval;
+            steps += 1;
+        }
+        steps
+    }
+
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// The time accumulated toward the next step.
+    pub fn accumulated(&self) -> Duration {
+        self.accumulated
+    }
+
+    pub fn into_board(self) -> Board {
+        self.board
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn steps_with_accumulated_time() {
+        let blinker: Board = ".....\n..O..\n..O..\n..O..\n.....\n".parse().unwrap();
+        let mut sim = TimedSim::new(blinker.clone(), Duration::from_millis(50));
+
+        let steps = [20, 20, 20, 20, 120, 0, 10].map(|ms| sim.update(Duration::from_millis(ms)));
+        assert_eq!([0, 0, 1, 0, 3, 0, 0], steps);
+        assert_eq!(Duration::from_millis(10), sim.accumulated());
+        // four steps in all bring the blinker back to its starting phase
+        assert_eq!(blinker, sim.into_board());
+    }
+}